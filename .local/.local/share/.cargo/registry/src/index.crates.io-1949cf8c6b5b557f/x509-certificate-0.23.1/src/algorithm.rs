@@ -7,13 +7,16 @@
 use {
     crate::{
         rfc3447::DigestInfo,
-        rfc5280::{AlgorithmIdentifier, AlgorithmParameter},
+        rfc5280::{AlgorithmIdentifier, AlgorithmParameter, SubjectPublicKeyInfo},
+        rfc5958::OneAsymmetricKey,
         X509CertificateError as Error,
     },
-    bcder::{encode::Values, ConstOid, OctetString, Oid},
-    ring::{digest, signature},
+    bcder::{decode::Constructed, encode::Values, string::BitString, ConstOid, Mode, OctetString, Oid},
+    bytes::Bytes,
+    ring::{digest, rand::SecureRandom, signature},
     spki::ObjectIdentifier,
     std::fmt::{Display, Formatter},
+    zeroize::Zeroizing,
 };
 
 /// SHA-1 digest algorithm.
@@ -61,6 +64,16 @@ const OID_SHA512_RSA: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 1, 13]);
 /// 1.2.840.113549.1.1.1
 const OID_RSA: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 1, 1]);
 
+/// RSASSA-PSS signature algorithm.
+///
+/// 1.2.840.113549.1.1.10
+const OID_RSASSA_PSS: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 1, 10]);
+
+/// MGF1 mask generation function, as used by `maskGenAlgorithm` in RSASSA-PSS-params.
+///
+/// 1.2.840.113549.1.1.8
+const OID_MGF1: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 1, 8]);
+
 /// ECDSA with SHA-256.
 ///
 /// 1.2.840.10045.4.3.2
@@ -96,11 +109,331 @@ pub(crate) const OID_EC_SECP256R1: ConstOid = Oid(&[42, 134, 72, 206, 61, 3, 1,
 /// 1.3.132.0.34
 pub(crate) const OID_EC_SECP384R1: ConstOid = Oid(&[43, 129, 4, 0, 34]);
 
+/// Elliptic curve identifier for secp256k1.
+///
+/// 1.3.132.0.10
+pub(crate) const OID_EC_SECP256K1: ConstOid = Oid(&[43, 129, 4, 0, 10]);
+
 /// No signature identifier
 /// 
 /// 1.3.6.1.5.5.7.6.2
 pub(crate) const OID_NO_SIGNATURE_ALGORITHM: ConstOid = Oid(&[43, 6, 1, 5, 5, 7, 6, 2]);
 
+/// An incremental hasher produced by a [CryptoBackend].
+///
+/// This is a backend-agnostic replacement for `ring`'s `digest::Context`,
+/// allowing [DigestAlgorithm::digester] to work the same way regardless of
+/// which [CryptoBackend] is in use.
+pub trait Digester: Send {
+    /// Feed more data into the hasher.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consume the hasher and obtain the final digest bytes.
+    fn finish(self: Box<Self>) -> Vec<u8>;
+}
+
+/// Provides the cryptographic primitives this crate relies on.
+///
+/// The default backend is implemented using `ring`. `ring`'s C/assembly
+/// code doesn't build in every environment (notably WebAssembly), so an
+/// alternate, pure-Rust backend implemented with the RustCrypto crates
+/// (`sha1`/`sha2`, `rsa`, `p256`/`p384`, `ed25519-dalek`) is available behind
+/// the `rustcrypto` cargo feature. [DigestAlgorithm::digester],
+/// [DigestAlgorithm::digest_data], and
+/// [SignatureAlgorithm::resolve_verification_algorithm] all dispatch through
+/// whichever backend is selected; the public [DigestAlgorithm],
+/// [SignatureAlgorithm], [KeyAlgorithm], and [EcdsaCurve] enums stay the same
+/// regardless of backend.
+pub trait CryptoBackend: Send + Sync {
+    /// Obtain an incremental hasher for `algorithm`.
+    fn digester(&self, algorithm: DigestAlgorithm) -> Box<dyn Digester>;
+
+    /// Resolve the [VerificationAlgorithm] for a signature/key algorithm combination.
+    fn resolve_verification_algorithm(
+        &self,
+        signature_algorithm: SignatureAlgorithm,
+        key_algorithm: KeyAlgorithm,
+    ) -> Result<VerificationAlgorithm, Error>;
+}
+
+/// Obtain the [CryptoBackend] this crate is configured to use.
+///
+/// This is a `ring`-backed implementation by default, or the RustCrypto-backed
+/// implementation when the `rustcrypto` cargo feature is enabled.
+fn backend() -> &'static dyn CryptoBackend {
+    #[cfg(feature = "rustcrypto")]
+    {
+        &RustCryptoBackend
+    }
+    #[cfg(not(feature = "rustcrypto"))]
+    {
+        &RingBackend
+    }
+}
+
+/// The default, `ring`-backed [CryptoBackend].
+struct RingBackend;
+
+impl Digester for digest::Context {
+    fn update(&mut self, data: &[u8]) {
+        digest::Context::update(self, data)
+    }
+
+    fn finish(self: Box<Self>) -> Vec<u8> {
+        digest::Context::finish(*self).as_ref().to_vec()
+    }
+}
+
+impl CryptoBackend for RingBackend {
+    fn digester(&self, algorithm: DigestAlgorithm) -> Box<dyn Digester> {
+        Box::new(digest::Context::from(algorithm))
+    }
+
+    fn resolve_verification_algorithm(
+        &self,
+        signature_algorithm: SignatureAlgorithm,
+        key_algorithm: KeyAlgorithm,
+    ) -> Result<VerificationAlgorithm, Error> {
+        signature_algorithm.resolve_ring_verification_algorithm(key_algorithm)
+    }
+}
+
+/// The pure-Rust, RustCrypto-backed [CryptoBackend].
+///
+/// Selected by the `rustcrypto` cargo feature in place of [RingBackend], for
+/// environments such as WebAssembly where `ring`'s C/assembly code won't build.
+#[cfg(feature = "rustcrypto")]
+struct RustCryptoBackend;
+
+#[cfg(feature = "rustcrypto")]
+enum RustCryptoDigester {
+    Sha1(sha1::Sha1),
+    Sha256(sha2::Sha256),
+    Sha384(sha2::Sha384),
+    Sha512(sha2::Sha512),
+}
+
+#[cfg(feature = "rustcrypto")]
+impl Digester for RustCryptoDigester {
+    fn update(&mut self, data: &[u8]) {
+        use sha2::Digest as _;
+
+        match self {
+            Self::Sha1(h) => sha1::Digest::update(h, data),
+            Self::Sha256(h) => sha2::Digest::update(h, data),
+            Self::Sha384(h) => sha2::Digest::update(h, data),
+            Self::Sha512(h) => sha2::Digest::update(h, data),
+        }
+    }
+
+    fn finish(self: Box<Self>) -> Vec<u8> {
+        use sha2::Digest as _;
+
+        match *self {
+            Self::Sha1(h) => sha1::Digest::finalize(h).to_vec(),
+            Self::Sha256(h) => sha2::Digest::finalize(h).to_vec(),
+            Self::Sha384(h) => sha2::Digest::finalize(h).to_vec(),
+            Self::Sha512(h) => sha2::Digest::finalize(h).to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+impl CryptoBackend for RustCryptoBackend {
+    fn digester(&self, algorithm: DigestAlgorithm) -> Box<dyn Digester> {
+        use sha2::Digest as _;
+
+        Box::new(match algorithm {
+            DigestAlgorithm::Sha1 => RustCryptoDigester::Sha1(sha1::Sha1::new()),
+            DigestAlgorithm::Sha256 => RustCryptoDigester::Sha256(sha2::Sha256::new()),
+            DigestAlgorithm::Sha384 => RustCryptoDigester::Sha384(sha2::Sha384::new()),
+            DigestAlgorithm::Sha512 => RustCryptoDigester::Sha512(sha2::Sha512::new()),
+        })
+    }
+
+    fn resolve_verification_algorithm(
+        &self,
+        signature_algorithm: SignatureAlgorithm,
+        key_algorithm: KeyAlgorithm,
+    ) -> Result<VerificationAlgorithm, Error> {
+        let verifier: Box<dyn RustCryptoVerifier> = match (key_algorithm, signature_algorithm) {
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaSha1) => {
+                Box::new(RsaPkcs1Verifier(DigestAlgorithm::Sha1))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaSha256) => {
+                Box::new(RsaPkcs1Verifier(DigestAlgorithm::Sha256))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaSha384) => {
+                Box::new(RsaPkcs1Verifier(DigestAlgorithm::Sha384))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaSha512) => {
+                Box::new(RsaPkcs1Verifier(DigestAlgorithm::Sha512))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaPssSha256) => {
+                Box::new(RsaPssVerifier(DigestAlgorithm::Sha256))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaPssSha384) => {
+                Box::new(RsaPssVerifier(DigestAlgorithm::Sha384))
+            }
+            (KeyAlgorithm::Rsa, SignatureAlgorithm::RsaPssSha512) => {
+                Box::new(RsaPssVerifier(DigestAlgorithm::Sha512))
+            }
+            (KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1), SignatureAlgorithm::EcdsaSha256) => {
+                Box::new(EcdsaP256Verifier)
+            }
+            (KeyAlgorithm::Ecdsa(EcdsaCurve::Secp384r1), SignatureAlgorithm::EcdsaSha384) => {
+                Box::new(EcdsaP384Verifier)
+            }
+            (KeyAlgorithm::Ed25519, SignatureAlgorithm::Ed25519) => Box::new(Ed25519Verifier),
+            (KeyAlgorithm::Ed25519, SignatureAlgorithm::Ed25519ph) => Box::new(Ed25519phVerifier),
+            (alg, key_alg) => return Err(Error::UnsupportedSignatureVerification(alg, key_alg)),
+        };
+
+        Ok(VerificationAlgorithm::RustCrypto(verifier))
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct RsaPkcs1Verifier(DigestAlgorithm);
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for RsaPkcs1Verifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use rsa::{pkcs1v15::VerifyingKey, signature::Verifier};
+
+        let key = rsa::RsaPublicKey::from_pkcs1_der(public_key)
+            .or_else(|_| rsa::RsaPublicKey::from_public_key_der(public_key))
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        match self.0 {
+            DigestAlgorithm::Sha1 => VerifyingKey::<sha1::Sha1>::new(key)
+                .verify(message, &signature.try_into().map_err(|_| Error::CertificateSignatureVerificationFailed)?)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha256 => VerifyingKey::<sha2::Sha256>::new(key)
+                .verify(message, &signature.try_into().map_err(|_| Error::CertificateSignatureVerificationFailed)?)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha384 => VerifyingKey::<sha2::Sha384>::new(key)
+                .verify(message, &signature.try_into().map_err(|_| Error::CertificateSignatureVerificationFailed)?)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha512 => VerifyingKey::<sha2::Sha512>::new(key)
+                .verify(message, &signature.try_into().map_err(|_| Error::CertificateSignatureVerificationFailed)?)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+        }
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct RsaPssVerifier(DigestAlgorithm);
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for RsaPssVerifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use rsa::{pss::VerifyingKey, signature::Verifier};
+
+        let key = rsa::RsaPublicKey::from_pkcs1_der(public_key)
+            .or_else(|_| rsa::RsaPublicKey::from_public_key_der(public_key))
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        let sig = signature
+            .try_into()
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        match self.0 {
+            DigestAlgorithm::Sha256 => VerifyingKey::<sha2::Sha256>::new(key)
+                .verify(message, &sig)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha384 => VerifyingKey::<sha2::Sha384>::new(key)
+                .verify(message, &sig)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha512 => VerifyingKey::<sha2::Sha512>::new(key)
+                .verify(message, &sig)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            DigestAlgorithm::Sha1 => Err(Error::UnknownSignatureAlgorithm(
+                "RSASSA-PSS with SHA-1 is not supported".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct EcdsaP256Verifier;
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for EcdsaP256Verifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use p256::ecdsa::signature::Verifier;
+
+        let verifying_key = p256::ecdsa::VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+        let sig = p256::ecdsa::Signature::from_der(signature)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        verifying_key
+            .verify(message, &sig)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct EcdsaP384Verifier;
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for EcdsaP384Verifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use p384::ecdsa::signature::Verifier;
+
+        let verifying_key = p384::ecdsa::VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+        let sig = p384::ecdsa::Signature::from_der(signature)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        verifying_key
+            .verify(message, &sig)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct Ed25519Verifier;
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for Ed25519Verifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use ed25519_dalek::Verifier;
+
+        let verifying_key = ed25519_dalek::VerifyingKey::try_from(public_key)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+        let sig = ed25519_dalek::Signature::try_from(signature)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        verifying_key
+            .verify(message, &sig)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+struct Ed25519phVerifier;
+
+#[cfg(feature = "rustcrypto")]
+impl RustCryptoVerifier for Ed25519phVerifier {
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        use sha2::{Digest as _, Sha512};
+
+        let verifying_key = ed25519_dalek::VerifyingKey::try_from(public_key)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+        let sig = ed25519_dalek::Signature::try_from(signature)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        let mut prehash = Sha512::new();
+        prehash.update(message);
+
+        verifying_key
+            .verify_prehashed(prehash, None, &sig)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+    }
+}
+
 /// A hashing algorithm used for digesting data.
 ///
 /// Instances can be converted to and from [Oid] via `From`/`Into`
@@ -110,7 +443,8 @@ pub(crate) const OID_NO_SIGNATURE_ALGORITHM: ConstOid = Oid(&[43, 6, 1, 5, 5, 7,
 /// which is commonly used to represent them in X.509 certificates.
 ///
 /// Instances can be converted into a [digest::Context] capable of computing
-/// digests via `From`/`Into`.
+/// digests via `From`/`Into`. Prefer [DigestAlgorithm::digester], which
+/// dispatches through the configured [CryptoBackend].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum DigestAlgorithm {
     /// SHA-1.
@@ -205,15 +539,17 @@ impl From<DigestAlgorithm> for digest::Context {
 
 impl DigestAlgorithm {
     /// Obtain an object that can be used to digest content using this algorithm.
-    pub fn digester(&self) -> digest::Context {
-        digest::Context::from(*self)
+    ///
+    /// Dispatches through the configured [CryptoBackend].
+    pub fn digester(&self) -> Box<dyn Digester> {
+        backend().digester(*self)
     }
 
     /// Digest a slice of data.
     pub fn digest_data(&self, data: &[u8]) -> Vec<u8> {
         let mut h = self.digester();
         h.update(data);
-        h.finish().as_ref().to_vec()
+        h.finish()
     }
 
     /// Digest content from a reader.
@@ -231,7 +567,7 @@ impl DigestAlgorithm {
             }
         }
 
-        Ok(h.finish().as_ref().to_vec())
+        Ok(h.finish())
     }
 
     /// Digest the content of a path.
@@ -239,6 +575,62 @@ impl DigestAlgorithm {
         self.digest_reader(&mut std::fs::File::open(path)?)
     }
 
+    /// Derive a stable key ID for a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// This is the lowercase hex encoding of this digest algorithm applied to
+    /// the canonical SPKI bytes, the same approach TUF-style metadata uses to
+    /// derive key IDs. It gives callers a canonical way to index, deduplicate,
+    /// and pin keys across certificates without reimplementing the SPKI
+    /// hashing themselves.
+    pub fn key_id(&self, spki_der: &[u8]) -> String {
+        hex::encode(self.digest_data(spki_der))
+    }
+
+    /// Derive a key ID for `spki_der`, trying each digest algorithm in
+    /// `preference` in order and returning the first one this [CryptoBackend]
+    /// supports.
+    ///
+    /// Every [DigestAlgorithm] variant is supported by both the `ring` and
+    /// `rustcrypto` backends today, so this only returns `None` if
+    /// `preference` is empty; it exists so that callers don't need updating
+    /// if a future backend narrows digest support.
+    pub fn key_id_with_preference(
+        spki_der: &[u8],
+        preference: &[DigestAlgorithm],
+    ) -> Option<(DigestAlgorithm, String)> {
+        preference
+            .iter()
+            .map(|algorithm| (*algorithm, algorithm.key_id(spki_der)))
+            .next()
+    }
+
+    /// Resolve a W3C XML Signature / XML Encryption digest method URI to a [DigestAlgorithm].
+    ///
+    /// These are the URIs SAML and XMLDSig documents use in a `DigestMethod`
+    /// element's `Algorithm` attribute.
+    pub fn from_xmldsig_uri(uri: &str) -> Result<Self, Error> {
+        match uri {
+            "http://www.w3.org/2000/09/xmldsig#sha1" => Ok(Self::Sha1),
+            "http://www.w3.org/2001/04/xmlenc#sha256" => Ok(Self::Sha256),
+            "http://www.w3.org/2001/04/xmldsig-more#sha384" => Ok(Self::Sha384),
+            "http://www.w3.org/2001/04/xmlenc#sha512" => Ok(Self::Sha512),
+            _ => Err(Error::UnknownSignatureAlgorithm(format!(
+                "unrecognized XMLDSig digest method URI: {}",
+                uri
+            ))),
+        }
+    }
+
+    /// The W3C XML Signature / XML Encryption digest method URI for this algorithm.
+    pub fn xmldsig_uri(&self) -> &'static str {
+        match self {
+            Self::Sha1 => "http://www.w3.org/2000/09/xmldsig#sha1",
+            Self::Sha256 => "http://www.w3.org/2001/04/xmlenc#sha256",
+            Self::Sha384 => "http://www.w3.org/2001/04/xmldsig-more#sha384",
+            Self::Sha512 => "http://www.w3.org/2001/04/xmlenc#sha512",
+        }
+    }
+
     /// EMSA-PKCS1-v1_5 padding procedure.
     ///
     /// As defined by https://tools.ietf.org/html/rfc3447#section-9.2.
@@ -286,6 +678,202 @@ impl DigestAlgorithm {
 
         Ok(res)
     }
+
+    /// The fixed ASN.1 `DigestInfo` prefix for this algorithm, not including the digest itself.
+    ///
+    /// This is the well-known DER encoding of
+    /// `SEQUENCE { AlgorithmIdentifier { oid, NULL }, OCTET STRING <digest length> }`,
+    /// up to but not including the digest octets. [Self::rsa_pkcs1_encode] builds the
+    /// same bytes via [DigestInfo] and `bcder`; this is a cheaper, allocation-free
+    /// equivalent for callers hand-rolling PKCS#1 v1.5 padding (e.g. a custom or
+    /// HSM-backed RSA signer) who just want `digest_info_prefix() || digest` without
+    /// pulling in the ASN.1 encoding machinery.
+    pub fn digest_info_prefix(&self) -> &'static [u8] {
+        match self {
+            Self::Sha1 => &[
+                0x30, 0x21, 0x30, 0x09, 0x06, 0x05, 0x2b, 0x0e, 0x03, 0x02, 0x1a, 0x05, 0x00,
+                0x04, 0x14,
+            ],
+            Self::Sha256 => &[
+                0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+                0x02, 0x01, 0x05, 0x00, 0x04, 0x20,
+            ],
+            Self::Sha384 => &[
+                0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+                0x02, 0x02, 0x05, 0x00, 0x04, 0x30,
+            ],
+            Self::Sha512 => &[
+                0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+                0x02, 0x03, 0x05, 0x00, 0x04, 0x40,
+            ],
+        }
+    }
+
+    /// The output length in bytes of this digest algorithm.
+    pub fn output_len(&self) -> usize {
+        match self {
+            Self::Sha1 => 20,
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+            Self::Sha512 => 64,
+        }
+    }
+
+    /// MGF1 mask generation function (RFC 3447 appendix B.2.1), using this digest algorithm as the hash.
+    fn mgf1(&self, seed: &[u8], mask_len: usize) -> Vec<u8> {
+        let mut mask = Vec::with_capacity(mask_len);
+
+        for counter in 0u32..=(mask_len / self.output_len()) as u32 {
+            let mut input = seed.to_vec();
+            input.extend_from_slice(&counter.to_be_bytes());
+            mask.extend_from_slice(&self.digest_data(&input));
+        }
+
+        mask.truncate(mask_len);
+        mask
+    }
+
+    /// EMSA-PSS encoding procedure.
+    ///
+    /// As defined by https://tools.ietf.org/html/rfc3447#section-9.1.1. This is
+    /// the RSASSA-PSS counterpart to [Self::rsa_pkcs1_encode], useful when
+    /// signing with a custom or HSM-backed RSA signer that only performs the
+    /// raw modular exponentiation and expects the caller to produce the
+    /// padded message representative.
+    ///
+    /// `message` is the message to digest and encode. `em_bits` is the target
+    /// encoded message length in bits, which should be `modulus_bits - 1` per
+    /// RFC 3447. `salt` is the salt to embed; the RSASSA-PSS-params profile
+    /// this crate uses elsewhere defaults the salt length to this digest's
+    /// output length, so callers without a specific requirement should pass
+    /// a salt of [Self::output_len] random bytes.
+    pub fn emsa_pss_encode(&self, message: &[u8], em_bits: usize, salt: &[u8]) -> Result<Vec<u8>, Error> {
+        self.emsa_pss_encode_from_hash(&self.digest_data(message), em_bits, salt)
+    }
+
+    /// Core of [Self::emsa_pss_encode] and [Self::rsa_pss_encode], operating on an
+    /// already-computed `mHash` rather than hashing the message itself.
+    fn emsa_pss_encode_from_hash(
+        &self,
+        m_hash: &[u8],
+        em_bits: usize,
+        salt: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let h_len = m_hash.len();
+        let s_len = salt.len();
+        let em_len = (em_bits + 7) / 8;
+
+        if em_len < h_len + s_len + 2 {
+            return Err(Error::PkcsEncodeTooShort);
+        }
+
+        let mut m_prime = Vec::with_capacity(8 + h_len + s_len);
+        m_prime.extend_from_slice(&[0u8; 8]);
+        m_prime.extend_from_slice(m_hash);
+        m_prime.extend_from_slice(salt);
+        let h = self.digest_data(&m_prime);
+
+        let ps_len = em_len - s_len - h_len - 2;
+        let mut db = Vec::with_capacity(em_len - h_len - 1);
+        db.extend(std::iter::repeat(0u8).take(ps_len));
+        db.push(0x01);
+        db.extend_from_slice(salt);
+
+        let db_mask = self.mgf1(&h, db.len());
+        let mut masked_db: Vec<u8> = db.iter().zip(&db_mask).map(|(a, b)| a ^ b).collect();
+
+        // Clear the leftmost 8*emLen - emBits bits of the leftmost octet.
+        let unused_bits = 8 * em_len - em_bits;
+        masked_db[0] &= 0xff >> unused_bits;
+
+        let mut em = masked_db;
+        em.extend_from_slice(&h);
+        em.push(0xbc);
+
+        Ok(em)
+    }
+
+    /// EMSA-PSS encoding procedure operating on a precomputed digest, with a random salt.
+    ///
+    /// This is the counterpart to [Self::emsa_pss_encode] for callers that already have
+    /// `mHash` (e.g. produced incrementally via [Self::digester]) rather than the full
+    /// message, and want this function to draw the salt for them. `salt_len` is typically
+    /// this digest's [Self::output_len], matching the RSASSA-PSS-params profile this crate
+    /// uses elsewhere. `rng` is a `ring` random number generator, e.g.
+    /// `ring::rand::SystemRandom::new()`.
+    pub fn rsa_pss_encode(
+        &self,
+        m_hash: &[u8],
+        em_bits: usize,
+        salt_len: usize,
+        rng: &dyn SecureRandom,
+    ) -> Result<Vec<u8>, Error> {
+        let mut salt = vec![0u8; salt_len];
+        // Reuse the key-pair generation error: both are "ring's RNG failed us",
+        // which practically never happens.
+        rng.fill(&mut salt)
+            .map_err(|_| Error::KeyPairGenerationError)?;
+
+        self.emsa_pss_encode_from_hash(m_hash, em_bits, &salt)
+    }
+
+    /// Verify an EMSA-PSS encoded message representative produced by [Self::rsa_pss_encode]
+    /// or [Self::emsa_pss_encode].
+    ///
+    /// `em` is the encoded message representative (e.g. the result of the RSA public key
+    /// raw modular exponentiation of a signature), `m_hash` is the digest of the original
+    /// message, and `salt_len` is the expected salt length.
+    pub fn rsa_pss_verify(
+        &self,
+        m_hash: &[u8],
+        em: &[u8],
+        em_bits: usize,
+        salt_len: usize,
+    ) -> Result<(), Error> {
+        let h_len = m_hash.len();
+        let em_len = (em_bits + 7) / 8;
+
+        if em.len() != em_len || em_len < h_len + salt_len + 2 {
+            return Err(Error::CertificateSignatureVerificationFailed);
+        }
+
+        if em[em.len() - 1] != 0xbc {
+            return Err(Error::CertificateSignatureVerificationFailed);
+        }
+
+        let db_len = em_len - h_len - 1;
+        let masked_db = &em[..db_len];
+        let h = &em[db_len..em.len() - 1];
+
+        let unused_bits = 8 * em_len - em_bits;
+        let top_mask = !(0xffu8 >> unused_bits);
+        if masked_db[0] & top_mask != 0 {
+            return Err(Error::CertificateSignatureVerificationFailed);
+        }
+
+        let db_mask = self.mgf1(h, db_len);
+        let mut db: Vec<u8> = masked_db.iter().zip(&db_mask).map(|(a, b)| a ^ b).collect();
+        db[0] &= 0xff >> unused_bits;
+
+        let ps_len = em_len - salt_len - h_len - 2;
+        if db[..ps_len].iter().any(|&b| b != 0) || db[ps_len] != 0x01 {
+            return Err(Error::CertificateSignatureVerificationFailed);
+        }
+
+        let salt = &db[ps_len + 1..];
+
+        let mut m_prime = Vec::with_capacity(8 + h_len + salt_len);
+        m_prime.extend_from_slice(&[0u8; 8]);
+        m_prime.extend_from_slice(m_hash);
+        m_prime.extend_from_slice(salt);
+        let h_prime = self.digest_data(&m_prime);
+
+        if h_prime == h {
+            Ok(())
+        } else {
+            Err(Error::CertificateSignatureVerificationFailed)
+        }
+    }
 }
 
 /// An algorithm used to digitally sign content.
@@ -295,9 +883,9 @@ impl DigestAlgorithm {
 /// Similarly, instances can be converted to/from an ASN.1
 /// [AlgorithmIdentifier].
 ///
-/// It is also possible to obtain a [signature::VerificationAlgorithm] from
-/// an instance. This type can perform actual cryptographic verification
-/// that was signed with this algorithm.
+/// It is also possible to obtain a [VerificationAlgorithm] from an instance.
+/// This type can perform actual cryptographic verification that was signed
+/// with this algorithm.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum SignatureAlgorithm {
     /// SHA-1 with RSA encryption.
@@ -335,12 +923,104 @@ pub enum SignatureAlgorithm {
     /// Corresponds to OID 1.3.101.112.
     Ed25519,
 
+    /// ED25519ph, the prehashed variant of ED25519 (RFC 8032 section 5.1).
+    ///
+    /// The content is hashed with SHA-512 before the Ed25519 signature
+    /// operation runs, as opposed to [Self::Ed25519] ("PureEdDSA"), which
+    /// signs/verifies the raw message directly. This is useful for
+    /// detached-signature and large-file signing flows, where the content
+    /// is hashed once and only the digest is fed to the signature
+    /// operation.
+    ///
+    /// RFC 8410 does not define a distinct OID for this variant, so it
+    /// cannot be resolved from a bare OID: `TryFrom<&Oid>` and
+    /// [Self::from_oid_and_digest_algorithm] will only ever produce
+    /// [Self::Ed25519]. Callers must select this variant explicitly when
+    /// they know they are dealing with a prehashed signature.
+    Ed25519ph,
+
+    /// RSASSA-PSS with SHA-256.
+    ///
+    /// Corresponds to OID 1.2.840.113549.1.1.10, with `RSASSA-PSS-params`
+    /// naming SHA-256 for both `hashAlgorithm` and `maskGenAlgorithm`.
+    RsaPssSha256,
+
+    /// RSASSA-PSS with SHA-384.
+    ///
+    /// Corresponds to OID 1.2.840.113549.1.1.10, with `RSASSA-PSS-params`
+    /// naming SHA-384 for both `hashAlgorithm` and `maskGenAlgorithm`.
+    RsaPssSha384,
+
+    /// RSASSA-PSS with SHA-512.
+    ///
+    /// Corresponds to OID 1.2.840.113549.1.1.10, with `RSASSA-PSS-params`
+    /// naming SHA-512 for both `hashAlgorithm` and `maskGenAlgorithm`.
+    RsaPssSha512,
+
     /// No signature with digest algorithm
     /// 
     /// Corresponds to OID 1.3.6.1.5.5.7.6.2
     NoSignature(DigestAlgorithm)
 }
 
+/// A resolved mechanism for verifying a cryptographic signature.
+///
+/// [SignatureAlgorithm::resolve_verification_algorithm] returns this instead of
+/// a bare [signature::VerificationAlgorithm] because not every curve this crate
+/// recognizes has a `ring` implementation. Notably, `ring` does not support
+/// secp256k1, so verification for that curve is routed through the `k256`
+/// crate instead, behind the `k256` cargo feature.
+pub enum VerificationAlgorithm {
+    /// Verify using one of `ring`'s built-in algorithms.
+    Ring(&'static dyn signature::VerificationAlgorithm),
+
+    /// Verify a DER-encoded ECDSA signature over secp256k1 with SHA-256, using
+    /// the `k256` crate.
+    #[cfg(feature = "k256")]
+    EcdsaSecp256k1Sha256,
+
+    /// Verify using the RustCrypto-backed [CryptoBackend], selected by the
+    /// `rustcrypto` cargo feature.
+    #[cfg(feature = "rustcrypto")]
+    RustCrypto(Box<dyn RustCryptoVerifier>),
+}
+
+impl VerificationAlgorithm {
+    /// Verify `signature` over `message`, using the raw `subjectPublicKey` bytes
+    /// of a `SubjectPublicKeyInfo` as `public_key`.
+    pub fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        match self {
+            Self::Ring(alg) => signature::UnparsedPublicKey::new(*alg, public_key)
+                .verify(message, signature)
+                .map_err(|_| Error::CertificateSignatureVerificationFailed),
+            #[cfg(feature = "k256")]
+            Self::EcdsaSecp256k1Sha256 => {
+                use k256::ecdsa::signature::Verifier;
+
+                let verifying_key = k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key)
+                    .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+                let sig = k256::ecdsa::Signature::from_der(signature)
+                    .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+                verifying_key
+                    .verify(message, &sig)
+                    .map_err(|_| Error::CertificateSignatureVerificationFailed)
+            }
+            #[cfg(feature = "rustcrypto")]
+            Self::RustCrypto(verifier) => verifier.verify(public_key, message, signature),
+        }
+    }
+}
+
+/// Verifies a signature using a RustCrypto crate.
+///
+/// Implemented per signature/key algorithm combination by [RustCryptoBackend].
+#[cfg(feature = "rustcrypto")]
+pub trait RustCryptoVerifier: Send + Sync {
+    /// Verify `signature` over `message`, using the raw `subjectPublicKey` bytes as `public_key`.
+    fn verify(&self, public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error>;
+}
+
 impl SignatureAlgorithm {
     /// Attempt to resolve an instance from an OID, known [KeyAlgorithm], and optional [DigestAlgorithm].
     ///
@@ -401,31 +1081,80 @@ impl SignatureAlgorithm {
     ///
     /// Only specific combinations of methods are supported. e.g. you can only use
     /// RSA verification with RSA signing keys. Same for ECDSA and ED25519.
+    ///
+    /// Dispatches through the configured [CryptoBackend].
     pub fn resolve_verification_algorithm(
         &self,
         key_algorithm: KeyAlgorithm,
-    ) -> Result<&'static dyn signature::VerificationAlgorithm, Error> {
+    ) -> Result<VerificationAlgorithm, Error> {
+        backend().resolve_verification_algorithm(*self, key_algorithm)
+    }
+
+    /// The `ring`-backed implementation of [Self::resolve_verification_algorithm], used by [RingBackend].
+    fn resolve_ring_verification_algorithm(
+        &self,
+        key_algorithm: KeyAlgorithm,
+    ) -> Result<VerificationAlgorithm, Error> {
         match key_algorithm {
             KeyAlgorithm::Rsa => match self {
-                Self::RsaSha1 => Ok(&signature::RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY),
-                Self::RsaSha256 => Ok(&signature::RSA_PKCS1_2048_8192_SHA256),
-                Self::RsaSha384 => Ok(&signature::RSA_PKCS1_2048_8192_SHA384),
-                Self::RsaSha512 => Ok(&signature::RSA_PKCS1_2048_8192_SHA512),
+                Self::RsaSha1 => Ok(VerificationAlgorithm::Ring(
+                    &signature::RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+                )),
+                Self::RsaSha256 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PKCS1_2048_8192_SHA256))
+                }
+                Self::RsaSha384 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PKCS1_2048_8192_SHA384))
+                }
+                Self::RsaSha512 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PKCS1_2048_8192_SHA512))
+                }
+                Self::RsaPssSha256 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PSS_2048_8192_SHA256))
+                }
+                Self::RsaPssSha384 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PSS_2048_8192_SHA384))
+                }
+                Self::RsaPssSha512 => {
+                    Ok(VerificationAlgorithm::Ring(&signature::RSA_PSS_2048_8192_SHA512))
+                }
                 alg => Err(Error::UnsupportedSignatureVerification(key_algorithm, *alg)),
             },
             KeyAlgorithm::Ed25519 => match self {
-                Self::Ed25519 => Ok(&signature::ED25519),
+                Self::Ed25519 => Ok(VerificationAlgorithm::Ring(&signature::ED25519)),
+                // ring only implements PureEdDSA verification, not the
+                // prehashed (Ed25519ph) variant.
                 alg => Err(Error::UnsupportedSignatureVerification(key_algorithm, *alg)),
             },
             KeyAlgorithm::Ecdsa(curve) => match curve {
                 EcdsaCurve::Secp256r1 => match self {
-                    Self::EcdsaSha256 => Ok(&signature::ECDSA_P256_SHA256_ASN1),
-                    Self::EcdsaSha384 => Ok(&signature::ECDSA_P256_SHA384_ASN1),
+                    Self::EcdsaSha256 => {
+                        Ok(VerificationAlgorithm::Ring(&signature::ECDSA_P256_SHA256_ASN1))
+                    }
+                    Self::EcdsaSha384 => {
+                        Ok(VerificationAlgorithm::Ring(&signature::ECDSA_P256_SHA384_ASN1))
+                    }
                     alg => Err(Error::UnsupportedSignatureVerification(key_algorithm, *alg)),
                 },
                 EcdsaCurve::Secp384r1 => match self {
-                    Self::EcdsaSha256 => Ok(&signature::ECDSA_P384_SHA256_ASN1),
-                    Self::EcdsaSha384 => Ok(&signature::ECDSA_P384_SHA384_ASN1),
+                    Self::EcdsaSha256 => {
+                        Ok(VerificationAlgorithm::Ring(&signature::ECDSA_P384_SHA256_ASN1))
+                    }
+                    Self::EcdsaSha384 => {
+                        Ok(VerificationAlgorithm::Ring(&signature::ECDSA_P384_SHA384_ASN1))
+                    }
+                    alg => Err(Error::UnsupportedSignatureVerification(key_algorithm, *alg)),
+                },
+                // ring has no secp256k1 support, so this is routed through the `k256`
+                // crate instead of a `ring` verification algorithm. Only SHA-256 is
+                // recognized, matching the digest the `k256` feature's verifier uses.
+                EcdsaCurve::Secp256k1 => match self {
+                    #[cfg(feature = "k256")]
+                    Self::EcdsaSha256 => Ok(VerificationAlgorithm::EcdsaSecp256k1Sha256),
+                    #[cfg(not(feature = "k256"))]
+                    Self::EcdsaSha256 => {
+                        Err(Error::UnsupportedSignatureVerification(key_algorithm, *self))
+                    }
                     alg => Err(Error::UnsupportedSignatureVerification(key_algorithm, *alg)),
                 },
             },
@@ -441,8 +1170,13 @@ impl SignatureAlgorithm {
             SignatureAlgorithm::RsaSha512 => Some(DigestAlgorithm::Sha512),
             SignatureAlgorithm::EcdsaSha256 => Some(DigestAlgorithm::Sha256),
             SignatureAlgorithm::EcdsaSha384 => Some(DigestAlgorithm::Sha384),
-            // TODO there's got to be a digest algorithm, right?
-            SignatureAlgorithm::Ed25519 => None,
+            SignatureAlgorithm::RsaPssSha256 => Some(DigestAlgorithm::Sha256),
+            SignatureAlgorithm::RsaPssSha384 => Some(DigestAlgorithm::Sha384),
+            SignatureAlgorithm::RsaPssSha512 => Some(DigestAlgorithm::Sha512),
+            // Ed25519 always hashes with SHA-512 internally, whether or not
+            // the caller is using the prehashed (Ed25519ph) variant.
+            SignatureAlgorithm::Ed25519 => Some(DigestAlgorithm::Sha512),
+            SignatureAlgorithm::Ed25519ph => Some(DigestAlgorithm::Sha512),
             SignatureAlgorithm::NoSignature(digest_algorithm) => Some(*digest_algorithm),
         }
     }
@@ -457,7 +1191,11 @@ impl Display for SignatureAlgorithm {
             SignatureAlgorithm::RsaSha512 => f.write_str("SHA-512 with RSA encryption"),
             SignatureAlgorithm::EcdsaSha256 => f.write_str("ECDSA with SHA-256"),
             SignatureAlgorithm::EcdsaSha384 => f.write_str("ECDSA with SHA-384"),
+            SignatureAlgorithm::RsaPssSha256 => f.write_str("RSASSA-PSS with SHA-256"),
+            SignatureAlgorithm::RsaPssSha384 => f.write_str("RSASSA-PSS with SHA-384"),
+            SignatureAlgorithm::RsaPssSha512 => f.write_str("RSASSA-PSS with SHA-512"),
             SignatureAlgorithm::Ed25519 => f.write_str("ED25519"),
+            SignatureAlgorithm::Ed25519ph => f.write_str("ED25519ph"),
             SignatureAlgorithm::NoSignature(digest_algorithm) => f.write_fmt(format_args!("No signature with {}", digest_algorithm)),
         }
     }
@@ -472,7 +1210,15 @@ impl From<SignatureAlgorithm> for Oid {
             SignatureAlgorithm::RsaSha512 => OID_SHA512_RSA.as_ref(),
             SignatureAlgorithm::EcdsaSha256 => OID_ECDSA_SHA256.as_ref(),
             SignatureAlgorithm::EcdsaSha384 => OID_ECDSA_SHA384.as_ref(),
-            SignatureAlgorithm::Ed25519 => OID_ED25519_SIGNATURE_ALGORITHM.as_ref(),
+            SignatureAlgorithm::RsaPssSha256
+            | SignatureAlgorithm::RsaPssSha384
+            | SignatureAlgorithm::RsaPssSha512 => OID_RSASSA_PSS.as_ref(),
+            // RFC 8410 has no distinct OID for the prehashed variant, so it
+            // shares the plain Ed25519 OID. The prehash mode is conveyed
+            // out-of-band by the caller, not by this OID.
+            SignatureAlgorithm::Ed25519 | SignatureAlgorithm::Ed25519ph => {
+                OID_ED25519_SIGNATURE_ALGORITHM.as_ref()
+            }
             SignatureAlgorithm::NoSignature(_) => OID_NO_SIGNATURE_ALGORITHM.as_ref(),
         }
         .into())
@@ -497,6 +1243,15 @@ impl TryFrom<&Oid> for SignatureAlgorithm {
             Ok(Self::EcdsaSha384)
         } else if v == &OID_ED25519_SIGNATURE_ALGORITHM {
             Ok(Self::Ed25519)
+        } else if v == &OID_RSASSA_PSS {
+            // The rsassaPss OID alone doesn't name a digest: the digest lives in
+            // the AlgorithmIdentifier's RSASSA-PSS-params parameters. Callers
+            // with just the OID should resolve via TryFrom<&AlgorithmIdentifier>
+            // instead.
+            Err(Error::UnknownSignatureAlgorithm(
+                "RSASSA-PSS requires AlgorithmIdentifier parameters to resolve its digest algorithm"
+                    .into(),
+            ))
         } else {
             Err(Error::UnknownSignatureAlgorithm(format!("{}", v)))
         }
@@ -507,17 +1262,245 @@ impl TryFrom<&AlgorithmIdentifier> for SignatureAlgorithm {
     type Error = Error;
 
     fn try_from(v: &AlgorithmIdentifier) -> Result<Self, Self::Error> {
-        Self::try_from(&v.algorithm)
+        if v.algorithm == OID_RSASSA_PSS {
+            Self::resolve_rsassa_pss(v.parameters.as_ref())
+        } else {
+            Self::try_from(&v.algorithm)
+        }
     }
 }
 
 impl From<SignatureAlgorithm> for AlgorithmIdentifier {
     fn from(alg: SignatureAlgorithm) -> Self {
+        let parameters = match alg {
+            SignatureAlgorithm::RsaPssSha256
+            | SignatureAlgorithm::RsaPssSha384
+            | SignatureAlgorithm::RsaPssSha512 => {
+                let digest = alg
+                    .digest_algorithm()
+                    .expect("RSASSA-PSS variants always have a digest algorithm");
+                let params_der = rsassa_pss_params_der(digest);
+
+                Some(
+                    Constructed::decode(params_der.as_slice(), Mode::Der, |cons| {
+                        AlgorithmParameter::take_from(cons)
+                    })
+                    .expect("locally constructed RSASSA-PSS-params should decode"),
+                )
+            }
+            _ => None,
+        };
+
         Self {
             algorithm: alg.into(),
-            parameters: None,
+            parameters,
+        }
+    }
+}
+
+/// Build DER for a minimal, fully explicit `RSASSA-PSS-params` SEQUENCE per
+/// RFC 4055 §3.1, naming `digest` as both the `hashAlgorithm` and the hash used
+/// by `maskGenAlgorithm` (MGF1), with `saltLength` set to the digest size and
+/// `trailerField` left at its default of `1` (and therefore omitted).
+fn rsassa_pss_params_der(digest: DigestAlgorithm) -> Vec<u8> {
+    let hash_oid: &[u8] = match digest {
+        DigestAlgorithm::Sha1 => OID_SHA1.as_ref(),
+        DigestAlgorithm::Sha256 => OID_SHA256.as_ref(),
+        DigestAlgorithm::Sha384 => OID_SHA384.as_ref(),
+        DigestAlgorithm::Sha512 => OID_SHA512.as_ref(),
+    };
+    let salt_length: u8 = match digest {
+        DigestAlgorithm::Sha1 => 20,
+        DigestAlgorithm::Sha256 => 32,
+        DigestAlgorithm::Sha384 => 48,
+        DigestAlgorithm::Sha512 => 64,
+    };
+
+    let hash_algorithm_id = der_algorithm_identifier(hash_oid, &der_tlv(0x05, &[]));
+    let mgf_algorithm_id = der_algorithm_identifier(OID_MGF1.as_ref(), &hash_algorithm_id);
+
+    let mut content = Vec::new();
+    content.extend_from_slice(&der_tlv(0xa0, &hash_algorithm_id));
+    content.extend_from_slice(&der_tlv(0xa1, &mgf_algorithm_id));
+    content.extend_from_slice(&der_tlv(0xa2, &der_tlv(0x02, &[salt_length])));
+
+    der_tlv(0x30, &content)
+}
+
+/// Resolve a [SignatureAlgorithm] from an rsassaPss `AlgorithmIdentifier`'s
+/// `RSASSA-PSS-params` parameters, per RFC 4055 §3.1.
+///
+/// Only the standard profile used by PS256/PS384/PS512 is accepted: MGF1 with
+/// the same hash as `hashAlgorithm`, a salt length equal to the digest size,
+/// and `trailerField` left at its default of `1`.
+fn resolve_rsassa_pss(params: Option<&AlgorithmParameter>) -> Result<SignatureAlgorithm, Error> {
+    let params = params.ok_or_else(|| {
+        Error::UnknownSignatureAlgorithm("rsassaPss AlgorithmIdentifier has no parameters".into())
+    })?;
+
+    let (tag, mut fields, _) = parse_der_tlv(params.as_slice())
+        .ok_or_else(|| Error::UnknownSignatureAlgorithm("malformed RSASSA-PSS-params".into()))?;
+    if tag != 0x30 {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "RSASSA-PSS-params is not a SEQUENCE".into(),
+        ));
+    }
+
+    let mut hash_algorithm = None;
+    let mut mgf_hash_algorithm = None;
+    let mut salt_length = None;
+    let mut trailer_field = None;
+
+    while !fields.is_empty() {
+        let (field_tag, content, rest) = parse_der_tlv(fields).ok_or_else(|| {
+            Error::UnknownSignatureAlgorithm("malformed RSASSA-PSS-params field".into())
+        })?;
+        fields = rest;
+
+        match field_tag {
+            0xa0 => {
+                let (oid, _) = algorithm_identifier_fields(content)?;
+                hash_algorithm = Some(DigestAlgorithm::try_from(&oid)?);
+            }
+            0xa1 => {
+                let (mgf_oid, mgf_params) = algorithm_identifier_fields(content)?;
+                if mgf_oid != OID_MGF1 {
+                    return Err(Error::UnknownSignatureAlgorithm(
+                        "RSASSA-PSS maskGenAlgorithm must be MGF1".into(),
+                    ));
+                }
+                let (inner_hash_oid, _) = algorithm_identifier_fields(mgf_params)?;
+                mgf_hash_algorithm = Some(DigestAlgorithm::try_from(&inner_hash_oid)?);
+            }
+            0xa2 => salt_length = Some(der_integer(content)),
+            0xa3 => trailer_field = Some(der_integer(content)),
+            _ => {}
+        }
+    }
+
+    let hash_algorithm = hash_algorithm.ok_or_else(|| {
+        Error::UnknownSignatureAlgorithm(
+            "only the explicit PS256/PS384/PS512 RSASSA-PSS-params profile is supported".into(),
+        )
+    })?;
+
+    if mgf_hash_algorithm != Some(hash_algorithm) {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "RSASSA-PSS maskGenAlgorithm hash must match hashAlgorithm".into(),
+        ));
+    }
+
+    let expected_salt_length: i64 = match hash_algorithm {
+        DigestAlgorithm::Sha1 => 20,
+        DigestAlgorithm::Sha256 => 32,
+        DigestAlgorithm::Sha384 => 48,
+        DigestAlgorithm::Sha512 => 64,
+    };
+    if salt_length.unwrap_or(expected_salt_length) != expected_salt_length {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "RSASSA-PSS saltLength must equal the digest size".into(),
+        ));
+    }
+
+    if trailer_field.unwrap_or(1) != 1 {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "RSASSA-PSS trailerField must be 1".into(),
+        ));
+    }
+
+    match hash_algorithm {
+        DigestAlgorithm::Sha256 => Ok(SignatureAlgorithm::RsaPssSha256),
+        DigestAlgorithm::Sha384 => Ok(SignatureAlgorithm::RsaPssSha384),
+        DigestAlgorithm::Sha512 => Ok(SignatureAlgorithm::RsaPssSha512),
+        DigestAlgorithm::Sha1 => Err(Error::UnknownSignatureAlgorithm(
+            "RSASSA-PSS with SHA-1 is not supported".into(),
+        )),
+    }
+}
+
+/// Parse the OID and raw parameters bytes out of a DER `AlgorithmIdentifier`
+/// SEQUENCE, without needing a full [AlgorithmIdentifier] decode.
+fn algorithm_identifier_fields(der: &[u8]) -> Result<(Oid, &[u8]), Error> {
+    let (tag, content, _) = parse_der_tlv(der)
+        .ok_or_else(|| Error::UnknownSignatureAlgorithm("malformed AlgorithmIdentifier".into()))?;
+    if tag != 0x30 {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "AlgorithmIdentifier is not a SEQUENCE".into(),
+        ));
+    }
+
+    let (oid_tag, oid_content, rest) = parse_der_tlv(content).ok_or_else(|| {
+        Error::UnknownSignatureAlgorithm("malformed AlgorithmIdentifier OID".into())
+    })?;
+    if oid_tag != 0x06 {
+        return Err(Error::UnknownSignatureAlgorithm(
+            "AlgorithmIdentifier does not begin with an OID".into(),
+        ));
+    }
+
+    Ok((Oid(oid_content.to_vec().into()), rest))
+}
+
+/// Interpret `content` (the value bytes of a DER INTEGER) as a big-endian integer.
+///
+/// Only used for small values (`saltLength`, `trailerField`), so overflow isn't a concern.
+fn der_integer(content: &[u8]) -> i64 {
+    content.iter().fold(0i64, |acc, &b| (acc << 8) | b as i64)
+}
+
+/// DER-encode a single TLV with an already-encoded `content`.
+fn der_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(content.len() + 2);
+    out.push(tag);
+    out.push(content.len() as u8);
+    out.extend_from_slice(content);
+
+    out
+}
+
+/// DER-encode an `AlgorithmIdentifier SEQUENCE { OID, parameters }`.
+fn der_algorithm_identifier(oid: &[u8], parameters: &[u8]) -> Vec<u8> {
+    let mut content = der_tlv(0x06, oid);
+    content.extend_from_slice(parameters);
+
+    der_tlv(0x30, &content)
+}
+
+/// Parse a single DER TLV, returning `(tag, content, remaining)`.
+///
+/// Supports short- and long-form lengths up to 4 length octets, which covers
+/// every structure this crate constructs or needs to inspect.
+fn parse_der_tlv(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    if data.len() < 2 {
+        return None;
+    }
+
+    let tag = data[0];
+
+    let (length, header_len) = if data[1] & 0x80 == 0 {
+        (data[1] as usize, 2)
+    } else {
+        let num_bytes = (data[1] & 0x7f) as usize;
+        if num_bytes == 0 || num_bytes > 4 || data.len() < 2 + num_bytes {
+            return None;
         }
+
+        let length = data[2..2 + num_bytes]
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+
+        (length, 2 + num_bytes)
+    };
+
+    if data.len() < header_len + length {
+        return None;
     }
+
+    Some((
+        tag,
+        &data[header_len..header_len + length],
+        &data[header_len + length..],
+    ))
 }
 
 /// Represents a known curve used with ECDSA.
@@ -525,12 +1508,19 @@ impl From<SignatureAlgorithm> for AlgorithmIdentifier {
 pub enum EcdsaCurve {
     Secp256r1,
     Secp384r1,
+
+    /// secp256k1, as used by blockchain and some IoT PKIs.
+    ///
+    /// `ring` has no signing (or verification) support for this curve, so it
+    /// is verification-only: see [SignatureAlgorithm::resolve_verification_algorithm]
+    /// and [VerificationAlgorithm].
+    Secp256k1,
 }
 
 impl EcdsaCurve {
     /// Obtain all variants of this type.
     pub fn all() -> &'static [Self] {
-        &[Self::Secp256r1, Self::Secp384r1]
+        &[Self::Secp256r1, Self::Secp384r1, Self::Secp256k1]
     }
 
     /// Obtain the OID representing this elliptic curve.
@@ -538,6 +1528,7 @@ impl EcdsaCurve {
         Oid(match self {
             Self::Secp256r1 => OID_EC_SECP256R1.as_ref().into(),
             Self::Secp384r1 => OID_EC_SECP384R1.as_ref().into(),
+            Self::Secp256k1 => OID_EC_SECP256K1.as_ref().into(),
         })
     }
 }
@@ -550,6 +1541,8 @@ impl TryFrom<&Oid> for EcdsaCurve {
             Ok(Self::Secp256r1)
         } else if v == &OID_EC_SECP384R1 {
             Ok(Self::Secp384r1)
+        } else if v == &OID_EC_SECP256K1 {
+            Ok(Self::Secp256k1)
         } else {
             Err(Error::UnknownEllipticCurve(format!("{}", v)))
         }
@@ -561,6 +1554,10 @@ impl From<EcdsaCurve> for &'static signature::EcdsaSigningAlgorithm {
         match curve {
             EcdsaCurve::Secp256r1 => &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
             EcdsaCurve::Secp384r1 => &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            EcdsaCurve::Secp256k1 => unreachable!(
+                "ring has no secp256k1 signing support; callers must reject this curve \
+                 before requesting a ring signing algorithm for it"
+            ),
         }
     }
 }
@@ -713,20 +1710,525 @@ impl From<KeyAlgorithm> for AlgorithmIdentifier {
     }
 }
 
+impl KeyAlgorithm {
+    /// Parse a DER-encoded `SubjectPublicKeyInfo` and recover the key algorithm and key bytes.
+    ///
+    /// The returned bytes are the raw `subjectPublicKey` BIT STRING content: a SEC1
+    /// point for ECDSA, the 32 raw bytes for Ed25519, or the DER-encoded `RSAPublicKey`
+    /// for RSA.
+    pub fn from_spki_der(data: &[u8]) -> Result<(Self, Vec<u8>), Error> {
+        let spki = Constructed::decode(data, Mode::Der, SubjectPublicKeyInfo::take_from)?;
+
+        let algorithm = Self::try_from(&spki.algorithm)?;
+        let key_bytes = spki.subject_public_key.octet_bytes().to_vec();
+
+        Ok((algorithm, key_bytes))
+    }
+
+    /// PEM variant of [Self::from_spki_der].
+    ///
+    /// The PEM should have a `PUBLIC KEY` tag, as is conventional for SPKI.
+    pub fn from_spki_pem(data: impl AsRef<[u8]>) -> Result<(Self, Vec<u8>), Error> {
+        let der = pem::parse(data.as_ref()).map_err(Error::PemDecode)?;
+
+        Self::from_spki_der(der.contents())
+    }
+
+    /// Encode this key algorithm and raw key bytes into a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// `key_bytes` should be in the same format [Self::from_spki_der] returns it in.
+    pub fn to_spki_der(&self, key_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        let spki = SubjectPublicKeyInfo {
+            algorithm: (*self).into(),
+            subject_public_key: BitString::new(0, Bytes::copy_from_slice(key_bytes)),
+        };
+
+        let mut out = vec![];
+        spki.encode_ref().write_encoded(Mode::Der, &mut out)?;
+
+        Ok(out)
+    }
+
+    /// PEM variant of [Self::to_spki_der], using the conventional `PUBLIC KEY` tag.
+    pub fn to_spki_pem(&self, key_bytes: &[u8]) -> Result<String, Error> {
+        Ok(pem::Pem::new("PUBLIC KEY", self.to_spki_der(key_bytes)?).to_string())
+    }
+
+    /// Parse a DER-encoded PKCS#8 `PrivateKeyInfo` / `OneAsymmetricKey` and recover the
+    /// key algorithm and raw private key bytes.
+    ///
+    /// This only inspects the algorithm identifier and private key octets; it does not
+    /// validate that the private key material itself is well-formed for the algorithm.
+    /// Use [crate::InMemorySigningKeyPair::from_pkcs8_der] to load a usable signing key.
+    pub fn from_pkcs8_der(data: &[u8]) -> Result<(Self, Zeroizing<Vec<u8>>), Error> {
+        let key = Constructed::decode(data, Mode::Der, OneAsymmetricKey::take_from)?;
+
+        let algorithm = Self::try_from(&key.private_key_algorithm)?;
+
+        Ok((algorithm, Zeroizing::new(key.private_key.into_bytes().to_vec())))
+    }
+
+    /// PEM variant of [Self::from_pkcs8_der].
+    ///
+    /// The PEM should have a `PRIVATE KEY` tag, as is conventional for PKCS#8.
+    pub fn from_pkcs8_pem(data: impl AsRef<[u8]>) -> Result<(Self, Zeroizing<Vec<u8>>), Error> {
+        let der = pem::parse(data.as_ref()).map_err(Error::PemDecode)?;
+
+        Self::from_pkcs8_der(der.contents())
+    }
+
+    /// Resolve the JOSE `alg` identifier for this key algorithm signing over `digest`.
+    ///
+    /// This only covers the conventional, non-PSS pairing for each key algorithm
+    /// (PKCS#1 v1.5 for RSA, ECDSA for the NIST curves, plain EdDSA for Ed25519):
+    /// there is no way to request `PS256`/`PS384`/`PS512` from a bare [KeyAlgorithm],
+    /// since RSA-PSS isn't distinguished from RSA PKCS#1 v1.5 at that level. Callers
+    /// who need a PSS `alg` should convert a [SignatureAlgorithm] via
+    /// [JwaAlgorithm::try_from] instead.
+    pub fn jwa_for_digest(&self, digest: DigestAlgorithm) -> Result<JwaAlgorithm, Error> {
+        match (self, digest) {
+            (Self::Rsa, DigestAlgorithm::Sha256) => Ok(JwaAlgorithm::Rs256),
+            (Self::Rsa, DigestAlgorithm::Sha384) => Ok(JwaAlgorithm::Rs384),
+            (Self::Rsa, DigestAlgorithm::Sha512) => Ok(JwaAlgorithm::Rs512),
+            (Self::Ecdsa(EcdsaCurve::Secp256r1), DigestAlgorithm::Sha256) => Ok(JwaAlgorithm::Es256),
+            (Self::Ecdsa(EcdsaCurve::Secp384r1), DigestAlgorithm::Sha384) => Ok(JwaAlgorithm::Es384),
+            (Self::Ed25519, _) => Ok(JwaAlgorithm::EdDsa),
+            (key_algorithm, digest) => Err(Error::UnknownSignatureAlgorithm(format!(
+                "no JWA alg identifier for {} with {} digest",
+                key_algorithm, digest
+            ))),
+        }
+    }
+}
+
+/// A JSON Web Algorithm (JWA, RFC 7518) `alg` identifier for a digital signature.
+///
+/// This bridges this crate's [KeyAlgorithm]/[DigestAlgorithm]/[SignatureAlgorithm]
+/// types to the string identifiers JOSE libraries (JWS/JWT) expect in the `alg`
+/// header, so callers don't have to hand-maintain their own lookup table.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum JwaAlgorithm {
+    /// RSASSA-PKCS1-v1_5 with SHA-256.
+    Rs256,
+
+    /// RSASSA-PKCS1-v1_5 with SHA-384.
+    Rs384,
+
+    /// RSASSA-PKCS1-v1_5 with SHA-512.
+    Rs512,
+
+    /// RSASSA-PSS with SHA-256, MGF1 with SHA-256, and a salt length equal to the
+    /// digest length.
+    Ps256,
+
+    /// RSASSA-PSS with SHA-384, MGF1 with SHA-384, and a salt length equal to the
+    /// digest length.
+    Ps384,
+
+    /// RSASSA-PSS with SHA-512, MGF1 with SHA-512, and a salt length equal to the
+    /// digest length.
+    Ps512,
+
+    /// ECDSA using the P-256 curve and SHA-256.
+    Es256,
+
+    /// ECDSA using the P-384 curve and SHA-384.
+    Es384,
+
+    /// EdDSA (pure Ed25519, per RFC 8037). JOSE has no `alg` for prehashed
+    /// Ed25519ph or for Ed448.
+    EdDsa,
+}
+
+impl Display for JwaAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Rs256 => "RS256",
+            Self::Rs384 => "RS384",
+            Self::Rs512 => "RS512",
+            Self::Ps256 => "PS256",
+            Self::Ps384 => "PS384",
+            Self::Ps512 => "PS512",
+            Self::Es256 => "ES256",
+            Self::Es384 => "ES384",
+            Self::EdDsa => "EdDSA",
+        })
+    }
+}
+
+impl std::str::FromStr for JwaAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "RS256" => Ok(Self::Rs256),
+            "RS384" => Ok(Self::Rs384),
+            "RS512" => Ok(Self::Rs512),
+            "PS256" => Ok(Self::Ps256),
+            "PS384" => Ok(Self::Ps384),
+            "PS512" => Ok(Self::Ps512),
+            "ES256" => Ok(Self::Es256),
+            "ES384" => Ok(Self::Es384),
+            "EdDSA" => Ok(Self::EdDsa),
+            _ => Err(Error::UnknownSignatureAlgorithm(format!(
+                "unrecognized JWA alg identifier: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl TryFrom<SignatureAlgorithm> for JwaAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: SignatureAlgorithm) -> Result<Self, Self::Error> {
+        match alg {
+            SignatureAlgorithm::RsaSha256 => Ok(Self::Rs256),
+            SignatureAlgorithm::RsaSha384 => Ok(Self::Rs384),
+            SignatureAlgorithm::RsaSha512 => Ok(Self::Rs512),
+            SignatureAlgorithm::RsaPssSha256 => Ok(Self::Ps256),
+            SignatureAlgorithm::RsaPssSha384 => Ok(Self::Ps384),
+            SignatureAlgorithm::RsaPssSha512 => Ok(Self::Ps512),
+            SignatureAlgorithm::EcdsaSha256 => Ok(Self::Es256),
+            SignatureAlgorithm::EcdsaSha384 => Ok(Self::Es384),
+            SignatureAlgorithm::Ed25519 => Ok(Self::EdDsa),
+            alg => Err(Error::UnknownSignatureAlgorithm(format!(
+                "{} has no corresponding JWA alg identifier",
+                alg
+            ))),
+        }
+    }
+}
+
+impl From<JwaAlgorithm> for SignatureAlgorithm {
+    fn from(alg: JwaAlgorithm) -> Self {
+        match alg {
+            JwaAlgorithm::Rs256 => Self::RsaSha256,
+            JwaAlgorithm::Rs384 => Self::RsaSha384,
+            JwaAlgorithm::Rs512 => Self::RsaSha512,
+            JwaAlgorithm::Ps256 => Self::RsaPssSha256,
+            JwaAlgorithm::Ps384 => Self::RsaPssSha384,
+            JwaAlgorithm::Ps512 => Self::RsaPssSha512,
+            JwaAlgorithm::Es256 => Self::EcdsaSha256,
+            JwaAlgorithm::Es384 => Self::EcdsaSha384,
+            JwaAlgorithm::EdDsa => Self::Ed25519,
+        }
+    }
+}
+
+/// A W3C XML Signature (XMLDSig) signature method URI, as used by SAML.
+///
+/// This bridges this crate's [KeyAlgorithm]/[DigestAlgorithm]/[SignatureAlgorithm]
+/// types to the `SignatureMethod`/`DigestMethod` URIs that appear in SAML
+/// assertions and XML Signature documents, so SAML/XMLDSig consumers can resolve
+/// a signature method straight to the concrete OID, curve, and digest this crate
+/// already models instead of maintaining a private `SigAlg` enum.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum XmlDsigAlgorithm {
+    /// `http://www.w3.org/2000/09/xmldsig#rsa-sha1`
+    RsaSha1,
+
+    /// `http://www.w3.org/2001/04/xmldsig-more#rsa-sha256`
+    RsaSha256,
+
+    /// `http://www.w3.org/2001/04/xmldsig-more#rsa-sha384`
+    RsaSha384,
+
+    /// `http://www.w3.org/2001/04/xmldsig-more#rsa-sha512`
+    RsaSha512,
+
+    /// `http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256`
+    EcdsaSha256,
+
+    /// `http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha384`
+    EcdsaSha384,
+}
+
+impl XmlDsigAlgorithm {
+    /// Resolve a signature method URI to an [XmlDsigAlgorithm].
+    pub fn from_uri(uri: &str) -> Result<Self, Error> {
+        match uri {
+            "http://www.w3.org/2000/09/xmldsig#rsa-sha1" => Ok(Self::RsaSha1),
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256" => Ok(Self::RsaSha256),
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384" => Ok(Self::RsaSha384),
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512" => Ok(Self::RsaSha512),
+            "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256" => Ok(Self::EcdsaSha256),
+            "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha384" => Ok(Self::EcdsaSha384),
+            _ => Err(Error::UnknownSignatureAlgorithm(format!(
+                "unrecognized XMLDSig signature method URI: {}",
+                uri
+            ))),
+        }
+    }
+
+    /// The signature method URI for this algorithm.
+    pub fn to_uri(&self) -> &'static str {
+        match self {
+            Self::RsaSha1 => "http://www.w3.org/2000/09/xmldsig#rsa-sha1",
+            Self::RsaSha256 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256",
+            Self::RsaSha384 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384",
+            Self::RsaSha512 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512",
+            Self::EcdsaSha256 => "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256",
+            Self::EcdsaSha384 => "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha384",
+        }
+    }
+
+    /// The key algorithm this signature method requires.
+    pub fn key_algorithm(&self) -> KeyAlgorithm {
+        match self {
+            Self::RsaSha1 | Self::RsaSha256 | Self::RsaSha384 | Self::RsaSha512 => KeyAlgorithm::Rsa,
+            Self::EcdsaSha256 => KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1),
+            Self::EcdsaSha384 => KeyAlgorithm::Ecdsa(EcdsaCurve::Secp384r1),
+        }
+    }
+
+    /// The digest algorithm this signature method requires.
+    pub fn digest_algorithm(&self) -> DigestAlgorithm {
+        match self {
+            Self::RsaSha1 => DigestAlgorithm::Sha1,
+            Self::RsaSha256 | Self::EcdsaSha256 => DigestAlgorithm::Sha256,
+            Self::RsaSha384 | Self::EcdsaSha384 => DigestAlgorithm::Sha384,
+            Self::RsaSha512 => DigestAlgorithm::Sha512,
+        }
+    }
+}
+
+impl TryFrom<SignatureAlgorithm> for XmlDsigAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: SignatureAlgorithm) -> Result<Self, Self::Error> {
+        match alg {
+            SignatureAlgorithm::RsaSha1 => Ok(Self::RsaSha1),
+            SignatureAlgorithm::RsaSha256 => Ok(Self::RsaSha256),
+            SignatureAlgorithm::RsaSha384 => Ok(Self::RsaSha384),
+            SignatureAlgorithm::RsaSha512 => Ok(Self::RsaSha512),
+            SignatureAlgorithm::EcdsaSha256 => Ok(Self::EcdsaSha256),
+            SignatureAlgorithm::EcdsaSha384 => Ok(Self::EcdsaSha384),
+            alg => Err(Error::UnknownSignatureAlgorithm(format!(
+                "{} has no corresponding XMLDSig signature method URI",
+                alg
+            ))),
+        }
+    }
+}
+
+impl From<XmlDsigAlgorithm> for SignatureAlgorithm {
+    fn from(alg: XmlDsigAlgorithm) -> Self {
+        match alg {
+            XmlDsigAlgorithm::RsaSha1 => Self::RsaSha1,
+            XmlDsigAlgorithm::RsaSha256 => Self::RsaSha256,
+            XmlDsigAlgorithm::RsaSha384 => Self::RsaSha384,
+            XmlDsigAlgorithm::RsaSha512 => Self::RsaSha512,
+            XmlDsigAlgorithm::EcdsaSha256 => Self::EcdsaSha256,
+            XmlDsigAlgorithm::EcdsaSha384 => Self::EcdsaSha384,
+        }
+    }
+}
+
+/// An RSA public key, for interop with formats this crate doesn't otherwise model.
+///
+/// This currently only supports the ActivityPub/OStatus "Magic Public Key"
+/// compact format (`RSA.<base64url(n)>.<base64url(e)>`) that Mastodon and
+/// other federation software use. It is a thin wrapper around [rsa::RsaPublicKey]
+/// so callers can move between that format and the DER-encoded `RSAPublicKey`
+/// bytes that [KeyAlgorithm::to_spki_der] / [KeyAlgorithm::from_spki_der] expect
+/// for [KeyAlgorithm::Rsa].
+#[cfg(feature = "rustcrypto")]
+pub struct RsaPublicKey(rsa::RsaPublicKey);
+
+#[cfg(feature = "rustcrypto")]
+impl RsaPublicKey {
+    /// Parse an ActivityPub/OStatus "Magic Public Key" string.
+    ///
+    /// The format is `RSA.<base64url(n)>.<base64url(e)>`, where `n` and `e` are
+    /// the RSA modulus and public exponent encoded as unpadded, big-endian,
+    /// base64url integers.
+    pub fn from_magic_public_key(s: &str) -> Result<Self, Error> {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+        let mut parts = s.split('.');
+
+        let tag = parts
+            .next()
+            .ok_or_else(|| Error::UnknownKeyAlgorithm("empty magic public key".into()))?;
+        if tag != "RSA" {
+            return Err(Error::UnknownKeyAlgorithm(format!(
+                "unsupported magic public key tag: {}",
+                tag
+            )));
+        }
+
+        let n_b64 = parts
+            .next()
+            .ok_or_else(|| Error::UnknownKeyAlgorithm("magic public key is missing a modulus".into()))?;
+        let e_b64 = parts
+            .next()
+            .ok_or_else(|| Error::UnknownKeyAlgorithm("magic public key is missing an exponent".into()))?;
+        if parts.next().is_some() {
+            return Err(Error::UnknownKeyAlgorithm(
+                "magic public key has trailing components".into(),
+            ));
+        }
+
+        let n_bytes = URL_SAFE_NO_PAD
+            .decode(n_b64)
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("invalid magic public key modulus: {}", e)))?;
+        let e_bytes = URL_SAFE_NO_PAD
+            .decode(e_b64)
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("invalid magic public key exponent: {}", e)))?;
+
+        let n = rsa::BigUint::from_bytes_be(&n_bytes);
+        let e = rsa::BigUint::from_bytes_be(&e_bytes);
+
+        let key = rsa::RsaPublicKey::new(n, e)
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("invalid RSA public key: {}", e)))?;
+
+        Ok(Self(key))
+    }
+
+    /// Serialize this key as an ActivityPub/OStatus "Magic Public Key" string.
+    pub fn to_magic_public_key(&self) -> String {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+        use rsa::traits::PublicKeyParts;
+
+        format!(
+            "RSA.{}.{}",
+            URL_SAFE_NO_PAD.encode(self.0.n().to_bytes_be()),
+            URL_SAFE_NO_PAD.encode(self.0.e().to_bytes_be()),
+        )
+    }
+
+    /// Encode this key as a DER-encoded `RSAPublicKey` (RFC 8017 Appendix A.1.1).
+    ///
+    /// The returned bytes are in the same format [KeyAlgorithm::to_spki_der] and
+    /// [KeyAlgorithm::from_spki_der] use for [KeyAlgorithm::Rsa].
+    pub fn to_pkcs1_der(&self) -> Result<Vec<u8>, Error> {
+        use rsa::pkcs1::EncodeRsaPublicKey;
+
+        self.0
+            .to_pkcs1_der()
+            .map(|doc| doc.as_bytes().to_vec())
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("failed to encode RSAPublicKey: {}", e)))
+    }
+
+    /// Parse a DER-encoded `RSAPublicKey` (RFC 8017 Appendix A.1.1).
+    pub fn from_pkcs1_der(data: &[u8]) -> Result<Self, Error> {
+        use rsa::pkcs1::DecodeRsaPublicKey;
+
+        rsa::RsaPublicKey::from_pkcs1_der(data)
+            .map(Self)
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("failed to decode RSAPublicKey: {}", e)))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::*;
+    use {super::*, crate::signing::Sign};
 
     #[test]
     fn digest_pkcs1() -> Result<(), Error> {
         let message = b"deadbeef";
-        let raw_digest = DigestAlgorithm::Sha256.digest_data(message);
 
-        // RSA 1024.
+        // RSA 1024, SHA-256.
+        let raw_digest = DigestAlgorithm::Sha256.digest_data(message);
         let encoded = DigestAlgorithm::Sha256.rsa_pkcs1_encode(message, 128)?;
         assert_eq!(&encoded[0..3], &[0x00, 0x01, 0xff]);
         assert_eq!(&encoded[96..], &raw_digest);
 
+        // RSA 2048, covering each digest algorithm's length.
+        for algorithm in [
+            DigestAlgorithm::Sha1,
+            DigestAlgorithm::Sha256,
+            DigestAlgorithm::Sha384,
+            DigestAlgorithm::Sha512,
+        ] {
+            let raw_digest = algorithm.digest_data(message);
+            let encoded = algorithm.rsa_pkcs1_encode(message, 256)?;
+
+            assert_eq!(encoded.len(), 256);
+            assert_eq!(&encoded[0..2], &[0x00, 0x01]);
+            assert_eq!(encoded[encoded.len() - 1 - raw_digest.len()..].len(), raw_digest.len() + 1);
+            assert_eq!(&encoded[encoded.len() - raw_digest.len()..], &raw_digest[..]);
+
+            // The DigestInfo prefix immediately precedes the raw digest.
+            let prefix = algorithm.digest_info_prefix();
+            let prefix_start = encoded.len() - raw_digest.len() - prefix.len();
+            assert_eq!(&encoded[prefix_start..encoded.len() - raw_digest.len()], prefix);
+
+            // NULL-terminated padding immediately precedes the DigestInfo.
+            assert_eq!(encoded[prefix_start - 1], 0x00);
+            assert!(encoded[2..prefix_start - 1].iter().all(|&b| b == 0xff));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn emsa_pss_encode_basic() -> Result<(), Error> {
+        let message = b"deadbeef";
+        let salt = vec![0x42; DigestAlgorithm::Sha256.output_len()];
+        let em_bits = 2047; // modulus_bits - 1, for a 2048-bit key
+
+        let encoded = DigestAlgorithm::Sha256.emsa_pss_encode(message, em_bits, &salt)?;
+        assert_eq!(encoded.len(), 256);
+        assert_eq!(*encoded.last().unwrap(), 0xbc);
+
+        let h_len = DigestAlgorithm::Sha256.output_len();
+        assert_eq!(&encoded[encoded.len() - 1 - h_len..encoded.len() - 1], {
+            let mut m_prime = vec![0u8; 8];
+            m_prime.extend_from_slice(&DigestAlgorithm::Sha256.digest_data(message));
+            m_prime.extend_from_slice(&salt);
+            &DigestAlgorithm::Sha256.digest_data(&m_prime)[..]
+        });
+
+        // emLen too small to hold hLen + sLen + 2.
+        assert!(DigestAlgorithm::Sha256.emsa_pss_encode(message, 15, &salt).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rsa_pss_encode_verify_roundtrip() -> Result<(), Error> {
+        let m_hash = DigestAlgorithm::Sha256.digest_data(b"deadbeef");
+        let em_bits = 2047;
+        let salt_len = DigestAlgorithm::Sha256.output_len();
+        let rng = ring::rand::SystemRandom::new();
+
+        let em = DigestAlgorithm::Sha256.rsa_pss_encode(&m_hash, em_bits, salt_len, &rng)?;
+        assert_eq!(em.len(), 256);
+
+        DigestAlgorithm::Sha256.rsa_pss_verify(&m_hash, &em, em_bits, salt_len)?;
+
+        let wrong_hash = DigestAlgorithm::Sha256.digest_data(b"not the message");
+        assert!(DigestAlgorithm::Sha256
+            .rsa_pss_verify(&wrong_hash, &em, em_bits, salt_len)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_algorithm_spki_der_roundtrip() -> Result<(), Error> {
+        let key_pair = crate::InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519)?;
+        let key_bytes = key_pair.public_key_data();
+
+        let der = KeyAlgorithm::Ed25519.to_spki_der(&key_bytes)?;
+        let (algorithm, recovered_bytes) = KeyAlgorithm::from_spki_der(&der)?;
+
+        assert_eq!(algorithm, KeyAlgorithm::Ed25519);
+        assert_eq!(recovered_bytes, key_bytes.to_vec());
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_algorithm_pkcs8_der_roundtrip() -> Result<(), Error> {
+        let key_pair = crate::InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519)?;
+        let pkcs8_der = key_pair.to_pkcs8_one_asymmetric_key_der();
+
+        let (algorithm, _private_key) = KeyAlgorithm::from_pkcs8_der(&pkcs8_der)?;
+        assert_eq!(algorithm, KeyAlgorithm::Ed25519);
+
         Ok(())
     }
 
@@ -752,4 +2254,232 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn ecdsa_curve_secp256k1_oid_roundtrip() -> Result<(), Error> {
+        let oid = EcdsaCurve::Secp256k1.as_signature_oid();
+        assert_eq!(format!("{}", oid), "1.3.132.0.10");
+        assert_eq!(EcdsaCurve::try_from(&oid)?, EcdsaCurve::Secp256k1);
+        assert!(EcdsaCurve::all().contains(&EcdsaCurve::Secp256k1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ring_backend_digester_matches_digest_data() {
+        let message = b"deadbeef";
+
+        for alg in [
+            DigestAlgorithm::Sha1,
+            DigestAlgorithm::Sha256,
+            DigestAlgorithm::Sha384,
+            DigestAlgorithm::Sha512,
+        ] {
+            let mut h = alg.digester();
+            h.update(message);
+
+            assert_eq!(h.finish(), alg.digest_data(message));
+        }
+    }
+
+    #[test]
+    fn ed25519ph_digest_algorithm() {
+        assert_eq!(
+            SignatureAlgorithm::Ed25519.digest_algorithm(),
+            Some(DigestAlgorithm::Sha512)
+        );
+        assert_eq!(
+            SignatureAlgorithm::Ed25519ph.digest_algorithm(),
+            Some(DigestAlgorithm::Sha512)
+        );
+
+        // RFC 8410 has no distinct OID for the prehashed variant, so a bare
+        // OID always resolves to pure Ed25519.
+        let oid = Oid::from(SignatureAlgorithm::Ed25519ph);
+        assert_eq!(format!("{}", oid), format!("{}", Oid::from(SignatureAlgorithm::Ed25519)));
+        assert_eq!(SignatureAlgorithm::try_from(&oid).unwrap(), SignatureAlgorithm::Ed25519);
+    }
+
+    #[test]
+    fn digest_algorithm_key_id() {
+        let spki = b"fake SubjectPublicKeyInfo bytes";
+
+        let key_id = DigestAlgorithm::Sha256.key_id(spki);
+        assert_eq!(key_id, hex::encode(DigestAlgorithm::Sha256.digest_data(spki)));
+        assert_eq!(key_id.len(), 64);
+
+        let (algorithm, preferred_id) = DigestAlgorithm::key_id_with_preference(
+            spki,
+            &[DigestAlgorithm::Sha512, DigestAlgorithm::Sha256],
+        )
+        .unwrap();
+        assert_eq!(algorithm, DigestAlgorithm::Sha512);
+        assert_eq!(preferred_id, DigestAlgorithm::Sha512.key_id(spki));
+
+        assert!(DigestAlgorithm::key_id_with_preference(spki, &[]).is_none());
+    }
+
+    #[test]
+    fn rsassa_pss_algorithm_identifier_roundtrip() -> Result<(), Error> {
+        for alg in [
+            SignatureAlgorithm::RsaPssSha256,
+            SignatureAlgorithm::RsaPssSha384,
+            SignatureAlgorithm::RsaPssSha512,
+        ] {
+            let identifier = AlgorithmIdentifier::from(alg);
+            assert_eq!(Oid::from(alg), identifier.algorithm);
+            assert!(identifier.parameters.is_some());
+
+            // The bare OID alone is ambiguous and should be rejected.
+            assert!(SignatureAlgorithm::try_from(&identifier.algorithm).is_err());
+
+            assert_eq!(SignatureAlgorithm::try_from(&identifier)?, alg);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn jwa_algorithm_string_roundtrip() -> Result<(), Error> {
+        for (alg, name) in [
+            (JwaAlgorithm::Rs256, "RS256"),
+            (JwaAlgorithm::Rs384, "RS384"),
+            (JwaAlgorithm::Rs512, "RS512"),
+            (JwaAlgorithm::Ps256, "PS256"),
+            (JwaAlgorithm::Ps384, "PS384"),
+            (JwaAlgorithm::Ps512, "PS512"),
+            (JwaAlgorithm::Es256, "ES256"),
+            (JwaAlgorithm::Es384, "ES384"),
+            (JwaAlgorithm::EdDsa, "EdDSA"),
+        ] {
+            assert_eq!(alg.to_string(), name);
+            assert_eq!(name.parse::<JwaAlgorithm>()?, alg);
+        }
+
+        assert!("HS256".parse::<JwaAlgorithm>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn jwa_algorithm_signature_algorithm_roundtrip() -> Result<(), Error> {
+        for alg in [
+            SignatureAlgorithm::RsaSha256,
+            SignatureAlgorithm::RsaSha384,
+            SignatureAlgorithm::RsaSha512,
+            SignatureAlgorithm::RsaPssSha256,
+            SignatureAlgorithm::RsaPssSha384,
+            SignatureAlgorithm::RsaPssSha512,
+            SignatureAlgorithm::EcdsaSha256,
+            SignatureAlgorithm::EcdsaSha384,
+            SignatureAlgorithm::Ed25519,
+        ] {
+            let jwa = JwaAlgorithm::try_from(alg)?;
+            assert_eq!(SignatureAlgorithm::from(jwa), alg);
+        }
+
+        // RsaSha1, Ed25519ph, and NoSignature have no JWA representation.
+        assert!(JwaAlgorithm::try_from(SignatureAlgorithm::RsaSha1).is_err());
+        assert!(JwaAlgorithm::try_from(SignatureAlgorithm::Ed25519ph).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_algorithm_jwa_for_digest() -> Result<(), Error> {
+        assert_eq!(
+            KeyAlgorithm::Rsa.jwa_for_digest(DigestAlgorithm::Sha256)?,
+            JwaAlgorithm::Rs256
+        );
+        assert_eq!(
+            KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1).jwa_for_digest(DigestAlgorithm::Sha256)?,
+            JwaAlgorithm::Es256
+        );
+        assert_eq!(
+            KeyAlgorithm::Ecdsa(EcdsaCurve::Secp384r1).jwa_for_digest(DigestAlgorithm::Sha384)?,
+            JwaAlgorithm::Es384
+        );
+        assert_eq!(
+            KeyAlgorithm::Ed25519.jwa_for_digest(DigestAlgorithm::Sha512)?,
+            JwaAlgorithm::EdDsa
+        );
+
+        // Mismatched curve/digest pairings have no JWA alg.
+        assert!(KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1)
+            .jwa_for_digest(DigestAlgorithm::Sha384)
+            .is_err());
+        assert!(KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256k1)
+            .jwa_for_digest(DigestAlgorithm::Sha256)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn digest_algorithm_xmldsig_uri_roundtrip() -> Result<(), Error> {
+        for (algorithm, uri) in [
+            (DigestAlgorithm::Sha1, "http://www.w3.org/2000/09/xmldsig#sha1"),
+            (DigestAlgorithm::Sha256, "http://www.w3.org/2001/04/xmlenc#sha256"),
+            (DigestAlgorithm::Sha384, "http://www.w3.org/2001/04/xmldsig-more#sha384"),
+            (DigestAlgorithm::Sha512, "http://www.w3.org/2001/04/xmlenc#sha512"),
+        ] {
+            assert_eq!(algorithm.xmldsig_uri(), uri);
+            assert_eq!(DigestAlgorithm::from_xmldsig_uri(uri)?, algorithm);
+        }
+
+        assert!(DigestAlgorithm::from_xmldsig_uri("http://example.com/sha256").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn xmldsig_algorithm_uri_roundtrip() -> Result<(), Error> {
+        for alg in [
+            XmlDsigAlgorithm::RsaSha1,
+            XmlDsigAlgorithm::RsaSha256,
+            XmlDsigAlgorithm::RsaSha384,
+            XmlDsigAlgorithm::RsaSha512,
+            XmlDsigAlgorithm::EcdsaSha256,
+            XmlDsigAlgorithm::EcdsaSha384,
+        ] {
+            let uri = alg.to_uri();
+            assert_eq!(XmlDsigAlgorithm::from_uri(uri)?, alg);
+
+            let signature_algorithm = SignatureAlgorithm::from(alg);
+            assert_eq!(XmlDsigAlgorithm::try_from(signature_algorithm)?, alg);
+            assert_eq!(signature_algorithm.digest_algorithm(), Some(alg.digest_algorithm()));
+        }
+
+        assert!(XmlDsigAlgorithm::from_uri("http://www.w3.org/2007/05/xmldsig-more#rsa-pss").is_err());
+        assert!(XmlDsigAlgorithm::try_from(SignatureAlgorithm::Ed25519).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn rsa_public_key_magic_public_key_roundtrip() -> Result<(), Error> {
+        use rsa::{traits::PublicKeyParts, RsaPrivateKey};
+
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("key generation");
+        let public_key = rsa::RsaPublicKey::from(&private_key);
+
+        let magic_key = RsaPublicKey(public_key).to_magic_public_key();
+        assert!(magic_key.starts_with("RSA."));
+        assert_eq!(magic_key.matches('.').count(), 2);
+
+        let parsed = RsaPublicKey::from_magic_public_key(&magic_key)?;
+        assert_eq!(parsed.0.n(), private_key.n());
+        assert_eq!(parsed.0.e(), private_key.e());
+
+        let der = parsed.to_pkcs1_der()?;
+        let reparsed = RsaPublicKey::from_pkcs1_der(&der)?;
+        assert_eq!(reparsed.0.n(), private_key.n());
+
+        assert!(RsaPublicKey::from_magic_public_key("RSA.onlyone").is_err());
+        assert!(RsaPublicKey::from_magic_public_key("DSA.AA.AA").is_err());
+
+        Ok(())
+    }
 }
@@ -8,7 +8,8 @@ use {
     crate::{
         algorithm::DigestAlgorithm, asn1time::Time, rfc2986, rfc3280::Name, rfc5280, rfc5652,
         rfc5958::Attributes, rfc8017::RsaPublicKey, signing::Sign, InMemorySigningKeyPair,
-        KeyAlgorithm, KeyInfoSigner, SignatureAlgorithm, X509CertificateError as Error,
+        KeyAlgorithm, KeyInfoSigner, SignatureAlgorithm, VerificationAlgorithm,
+        X509CertificateError as Error,
     },
     bcder::{
         decode::Constructed,
@@ -20,7 +21,6 @@ use {
     bytes::Bytes,
     chrono::{DateTime, Duration, Utc},
     der::{Decode, Document},
-    ring::signature as ringsig,
     signature::Signer,
     spki::EncodePublicKey,
     std::{
@@ -29,6 +29,7 @@ use {
         fmt::{Debug, Formatter},
         hash::{Hash, Hasher},
         io::Write,
+        net::IpAddr,
         ops::{Deref, DerefMut},
     },
 };
@@ -38,11 +39,36 @@ use {
 /// 2.5.29.15
 const OID_EXTENSION_KEY_USAGE: ConstOid = Oid(&[85, 29, 15]);
 
+/// Subject Alternative Name extension.
+///
+/// 2.5.29.17
+const OID_EXTENSION_SUBJECT_ALT_NAME: ConstOid = Oid(&[85, 29, 17]);
+
 /// Basic Constraints X.509 extension.
 ///
 /// 2.5.29.19
 const OID_EXTENSION_BASIC_CONSTRAINTS: ConstOid = Oid(&[85, 29, 19]);
 
+/// Extended Key Usage extension.
+///
+/// 2.5.29.37
+const OID_EXTENSION_EXTENDED_KEY_USAGE: ConstOid = Oid(&[85, 29, 37]);
+
+/// Subject Key Identifier extension.
+///
+/// 2.5.29.14
+const OID_EXTENSION_SUBJECT_KEY_IDENTIFIER: ConstOid = Oid(&[85, 29, 14]);
+
+/// Authority Key Identifier extension.
+///
+/// 2.5.29.35
+const OID_EXTENSION_AUTHORITY_KEY_IDENTIFIER: ConstOid = Oid(&[85, 29, 35]);
+
+/// PKCS#9 `extensionRequest` CSR attribute.
+///
+/// 1.2.840.113549.1.9.14
+const OID_PKCS9_EXTENSION_REQUEST: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 9, 14]);
+
 /// Provides an interface to the RFC 5280 [rfc5280::Certificate] ASN.1 type.
 ///
 /// This type provides the main high-level API that this crate exposes
@@ -320,10 +346,7 @@ impl X509Certificate {
     }
 
     /// Obtain the fingerprint for this certificate given a digest algorithm.
-    pub fn fingerprint(
-        &self,
-        algorithm: DigestAlgorithm,
-    ) -> Result<ring::digest::Digest, std::io::Error> {
+    pub fn fingerprint(&self, algorithm: DigestAlgorithm) -> Result<Vec<u8>, std::io::Error> {
         let raw = self.encode_der()?;
 
         let mut h = algorithm.digester();
@@ -333,12 +356,12 @@ impl X509Certificate {
     }
 
     /// Obtain the SHA-1 fingerprint of this certificate.
-    pub fn sha1_fingerprint(&self) -> Result<ring::digest::Digest, std::io::Error> {
+    pub fn sha1_fingerprint(&self) -> Result<Vec<u8>, std::io::Error> {
         self.fingerprint(DigestAlgorithm::Sha1)
     }
 
     /// Obtain the SHA-256 fingerprint of this certificate.
-    pub fn sha256_fingerprint(&self) -> Result<ring::digest::Digest, std::io::Error> {
+    pub fn sha256_fingerprint(&self) -> Result<Vec<u8>, std::io::Error> {
         self.fingerprint(DigestAlgorithm::Sha256)
     }
 
@@ -370,6 +393,194 @@ impl X509Certificate {
 
         compare_time >= self.validity_not_before() && compare_time <= self.validity_not_after()
     }
+
+    /// Locate an extension by OID and return its raw value bytes along with its `critical` flag.
+    fn find_extension(&self, oid: &ConstOid) -> Option<(Vec<u8>, bool)> {
+        self.iter_extensions()
+            .find(|ext| &ext.id == oid)
+            .map(|ext| (ext.value.to_bytes().to_vec(), ext.critical.unwrap_or(false)))
+    }
+
+    /// Obtain the decoded `SubjectAlternativeName` extension (2.5.29.17), if present.
+    ///
+    /// Returns `None` if the extension isn't present or couldn't be decoded.
+    pub fn subject_alternative_names(&self) -> Option<Vec<GeneralName>> {
+        let (value, _critical) = self.find_extension(&OID_EXTENSION_SUBJECT_ALT_NAME)?;
+
+        decode_general_names(&value)
+    }
+
+    /// Obtain the decoded `KeyUsage` extension (2.5.29.15), if present.
+    pub fn key_usage(&self) -> Option<KeyUsageFlags> {
+        let (value, critical) = self.find_extension(&OID_EXTENSION_KEY_USAGE)?;
+
+        if value.len() < 4 || value[0] != 0x03 {
+            return None;
+        }
+
+        let bits = value[3];
+
+        Some(KeyUsageFlags {
+            digital_signature: bits & 0x80 != 0,
+            non_repudiation: bits & 0x40 != 0,
+            key_encipherment: bits & 0x20 != 0,
+            data_encipherment: bits & 0x10 != 0,
+            key_agreement: bits & 0x08 != 0,
+            key_cert_sign: bits & 0x04 != 0,
+            crl_sign: bits & 0x02 != 0,
+            critical,
+        })
+    }
+
+    /// Obtain the set of OIDs declared in the `ExtendedKeyUsage` extension (2.5.29.37), if present.
+    pub fn extended_key_usage(&self) -> Option<Vec<Oid>> {
+        let (value, _critical) = self.find_extension(&OID_EXTENSION_EXTENDED_KEY_USAGE)?;
+
+        let (tag, mut rest, _) = parse_tlv(&value)?;
+        if tag != 0x30 {
+            return None;
+        }
+
+        let mut oids = vec![];
+
+        while !rest.is_empty() {
+            let (tag, oid_bytes, after) = parse_tlv(rest)?;
+            if tag != 0x06 {
+                break;
+            }
+
+            oids.push(Oid(Bytes::copy_from_slice(oid_bytes)));
+            rest = after;
+        }
+
+        Some(oids)
+    }
+
+    /// Obtain the decoded `BasicConstraints` extension (2.5.29.19), if present.
+    pub fn basic_constraints(&self) -> Option<BasicConstraints> {
+        let (value, critical) = self.find_extension(&OID_EXTENSION_BASIC_CONSTRAINTS)?;
+        let (ca, path_len) = decode_basic_constraints(&value)?;
+
+        Some(BasicConstraints {
+            ca,
+            path_len,
+            critical,
+        })
+    }
+}
+
+/// A decoded `GeneralName` as used in `SubjectAlternativeName` (and other) extensions.
+///
+/// Only the variants commonly consumed by TLS/codesigning hostname verification
+/// are decoded; other `GeneralName` choices are reported as [GeneralName::Other].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GeneralName {
+    /// `rfc822Name`: an RFC 822 (email) address.
+    Rfc822Name(String),
+
+    /// `dNSName`: a DNS name, potentially containing wildcards.
+    DnsName(String),
+
+    /// `uniformResourceIdentifier`: a URI.
+    Uri(String),
+
+    /// `iPAddress`: a raw IPv4 (4 bytes) or IPv6 (16 bytes) address.
+    IpAddress(Vec<u8>),
+
+    /// A `GeneralName` choice this crate doesn't decode, identified by its implicit tag number.
+    Other(u8),
+}
+
+/// Parse a DER `SEQUENCE OF GeneralName` into [GeneralName] values.
+fn decode_general_names(value: &[u8]) -> Option<Vec<GeneralName>> {
+    let (tag, mut rest, _) = parse_tlv(value)?;
+    if tag != 0x30 {
+        return None;
+    }
+
+    let mut names = vec![];
+
+    while !rest.is_empty() {
+        let (tag, content, after) = parse_tlv(rest)?;
+
+        let name = match tag {
+            // [1] IMPLICIT IA5String: rfc822Name.
+            0x81 => GeneralName::Rfc822Name(String::from_utf8_lossy(content).into_owned()),
+            // [2] IMPLICIT IA5String: dNSName.
+            0x82 => GeneralName::DnsName(String::from_utf8_lossy(content).into_owned()),
+            // [6] IMPLICIT IA5String: uniformResourceIdentifier.
+            0x86 => GeneralName::Uri(String::from_utf8_lossy(content).into_owned()),
+            // [7] IMPLICIT OCTET STRING: iPAddress.
+            0x87 => GeneralName::IpAddress(content.to_vec()),
+            _ => GeneralName::Other(tag & 0x1f),
+        };
+
+        names.push(name);
+        rest = after;
+    }
+
+    Some(names)
+}
+
+/// Encode [GeneralName] values into a DER `SEQUENCE OF GeneralName`.
+///
+/// [GeneralName::Other] entries have no recoverable content and are skipped.
+fn encode_general_names(names: &[GeneralName]) -> Vec<u8> {
+    let mut content = Vec::new();
+
+    for name in names {
+        let (tag, bytes): (u8, &[u8]) = match name {
+            GeneralName::Rfc822Name(s) => (0x81, s.as_bytes()),
+            GeneralName::DnsName(s) => (0x82, s.as_bytes()),
+            GeneralName::Uri(s) => (0x86, s.as_bytes()),
+            GeneralName::IpAddress(bytes) => (0x87, bytes.as_slice()),
+            GeneralName::Other(_) => continue,
+        };
+
+        content.extend_from_slice(&encode_tlv(tag, bytes));
+    }
+
+    encode_tlv(0x30, &content)
+}
+
+/// A convenience grouping of Subject Alternative Name values by kind, for use
+/// with [X509CertificateBuilder::san].
+#[derive(Clone, Debug, Default)]
+pub struct SubjectAltNames {
+    pub dns_names: Vec<String>,
+    pub ip_addresses: Vec<IpAddr>,
+    pub email_addresses: Vec<String>,
+    pub uris: Vec<String>,
+}
+
+/// Decoded view of the `KeyUsage` extension (2.5.29.15).
+///
+/// Each field corresponds to a bit in the underlying `KeyUsage` `BIT STRING`,
+/// per RFC 5280 §4.2.1.3.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyUsageFlags {
+    pub digital_signature: bool,
+    pub non_repudiation: bool,
+    pub key_encipherment: bool,
+    pub data_encipherment: bool,
+    pub key_agreement: bool,
+    pub key_cert_sign: bool,
+    pub crl_sign: bool,
+    /// Whether the extension was marked `critical`.
+    pub critical: bool,
+}
+
+/// Decoded view of the `BasicConstraints` extension (2.5.29.19).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BasicConstraints {
+    /// Whether the certificate may act as a CA.
+    pub ca: bool,
+
+    /// The maximum number of intermediate CA certificates permitted below this one.
+    pub path_len: Option<u32>,
+
+    /// Whether the extension was marked `critical`.
+    pub critical: bool,
 }
 
 impl From<rfc5280::Certificate> for X509Certificate {
@@ -495,6 +706,10 @@ impl CapturedX509Certificate {
     /// entries and silently ignore unknown ones. If you would like to specify
     /// an alternate set of tags (this is the value after the `BEGIN`) to search,
     /// call [Self::from_pem_multiple_tags].
+    ///
+    /// The returned `Vec` can be fed straight into [Self::resolve_signing_chain]
+    /// (e.g. `some_cert.resolve_signing_chain(bundle.iter())`) to order a PEM
+    /// bundle of intermediates into a signing chain.
     pub fn from_pem_multiple(data: impl AsRef<[u8]>) -> Result<Vec<Self>, Error> {
         Self::from_pem_multiple_tags(data, &["CERTIFICATE"])
     }
@@ -583,13 +798,9 @@ impl CapturedX509Certificate {
         &self,
         signed_data: impl AsRef<[u8]>,
         signature: impl AsRef<[u8]>,
-        verify_algorithm: &'static dyn ringsig::VerificationAlgorithm,
+        verify_algorithm: VerificationAlgorithm,
     ) -> Result<(), Error> {
-        let public_key = ringsig::UnparsedPublicKey::new(verify_algorithm, self.public_key_data());
-
-        public_key
-            .verify(signed_data.as_ref(), signature.as_ref())
-            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+        verify_algorithm.verify(&self.public_key_data(), signed_data.as_ref(), signature.as_ref())
     }
 
     /// Verifies that this certificate was cryptographically signed using raw public key data from a signing key.
@@ -634,11 +845,7 @@ impl CapturedX509Certificate {
 
         let verify_algorithm = signature_algorithm.resolve_verification_algorithm(key_algorithm)?;
 
-        let public_key = ringsig::UnparsedPublicKey::new(verify_algorithm, public_key_data);
-
-        public_key
-            .verify(signed_data, &signature)
-            .map_err(|_| Error::CertificateSignatureVerificationFailed)
+        verify_algorithm.verify(public_key_data.as_ref(), signed_data, &signature)
     }
 
     /// Attempt to find the issuing certificate of this one.
@@ -863,6 +1070,201 @@ impl From<KeyUsage> for u8 {
     }
 }
 
+/// A set of `KeyUsage` bits, combinable via bitwise OR.
+///
+/// [X509CertificateBuilder::key_usage] pushes a brand new extension per call, so
+/// requesting more than one bit (e.g. `digitalSignature` and `keyCertSign`) produces
+/// two conflicting `KeyUsage` extensions and an invalid certificate. `KeyUsages`
+/// lets callers combine bits into the single BIT STRING RFC 5280 actually expects;
+/// pass the result to [X509CertificateBuilder::key_usages].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct KeyUsages(u8);
+
+impl KeyUsages {
+    pub const DIGITAL_SIGNATURE: Self = Self(0x80);
+    pub const NON_REPUDIATION: Self = Self(0x40);
+    pub const KEY_ENCIPHERMENT: Self = Self(0x20);
+    pub const DATA_ENCIPHERMENT: Self = Self(0x10);
+    pub const KEY_AGREEMENT: Self = Self(0x08);
+    pub const KEY_CERT_SIGN: Self = Self(0x04);
+    pub const CRL_SIGN: Self = Self(0x02);
+}
+
+impl From<KeyUsage> for KeyUsages {
+    fn from(ku: KeyUsage) -> Self {
+        let bit: u8 = ku.into();
+        Self(0x80 >> bit)
+    }
+}
+
+impl std::ops::BitOr for KeyUsages {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for KeyUsages {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Encode a `KeyUsage` extension from a set of bits.
+///
+/// Trims trailing (low-order) zero bits from the `BIT STRING` and records the
+/// correct unused-bits count, per DER's canonical `BIT STRING` encoding.
+fn key_usage_bits_extension(usages: KeyUsages) -> rfc5280::Extension {
+    let bits = usages.0;
+
+    let mut unused_bits = 0u8;
+    for i in 0..8 {
+        if bits & (1 << i) == 0 {
+            unused_bits += 1;
+        } else {
+            break;
+        }
+    }
+
+    rfc5280::Extension {
+        id: Oid(OID_EXTENSION_KEY_USAGE.as_ref().into()),
+        critical: Some(true),
+        value: OctetString::new(Bytes::copy_from_slice(&[0x03, 0x02, unused_bits, bits])),
+    }
+}
+
+/// Encode a `BasicConstraints` extension.
+fn basic_constraints_extension(ca: bool, path_len: Option<u32>) -> rfc5280::Extension {
+    let mut content = Vec::new();
+
+    if ca {
+        content.extend_from_slice(&[0x01, 0x01, 0xff]);
+    }
+
+    if let Some(path_len) = path_len {
+        let bytes = path_len.to_be_bytes();
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+        let significant = &bytes[first_nonzero..];
+
+        content.push(0x02);
+        content.push(significant.len() as u8);
+        content.extend_from_slice(significant);
+    }
+
+    let mut value = Vec::with_capacity(content.len() + 2);
+    value.push(0x30);
+    value.push(content.len() as u8);
+    value.extend_from_slice(&content);
+
+    rfc5280::Extension {
+        id: Oid(OID_EXTENSION_BASIC_CONSTRAINTS.as_ref().into()),
+        critical: Some(true),
+        value: OctetString::new(Bytes::copy_from_slice(&value)),
+    }
+}
+
+/// DER-encode an `OCTET STRING` wrapping `content`.
+///
+/// Only handles short-form lengths, which is sufficient for the fixed-size
+/// key identifiers this is used for.
+fn der_octet_string(content: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(content.len() + 2);
+    out.push(0x04);
+    out.push(content.len() as u8);
+    out.extend_from_slice(content);
+
+    out
+}
+
+/// DER-encode an `AuthorityKeyIdentifier` SEQUENCE wrapping `key_id` in its
+/// `keyIdentifier [0]` IMPLICIT field.
+fn der_authority_key_identifier(key_id: &[u8]) -> Vec<u8> {
+    let mut inner = Vec::with_capacity(key_id.len() + 2);
+    inner.push(0x80);
+    inner.push(key_id.len() as u8);
+    inner.extend_from_slice(key_id);
+
+    let mut out = Vec::with_capacity(inner.len() + 2);
+    out.push(0x30);
+    out.push(inner.len() as u8);
+    out.extend_from_slice(&inner);
+
+    out
+}
+
+/// How a key identifier is derived from a public key, per RFC 5280 §4.2.1.2.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyIdGenMethod {
+    /// Method (1): the full 160-bit SHA-1 digest of the key's BIT STRING content
+    /// bytes (i.e. the key material, excluding the ASN.1 tag/length and the
+    /// unused-bits octet).
+    Sha1,
+
+    /// Method (2): the 4-bit value `0100` followed by the low-order 60 bits of
+    /// the SHA-1 digest of the key material.
+    Sha1Truncated,
+}
+
+impl KeyIdGenMethod {
+    fn generate(self, public_key_data: &[u8]) -> Vec<u8> {
+        let digest = DigestAlgorithm::Sha1.digest_data(public_key_data);
+
+        match self {
+            Self::Sha1 => digest,
+            Self::Sha1Truncated => {
+                let mut id = digest[digest.len() - 8..].to_vec();
+                id[0] = (id[0] & 0x0f) | 0x40;
+                id
+            }
+        }
+    }
+}
+
+/// A certificate usage profile that auto-populates the correct extension set.
+///
+/// Selecting a profile via [X509CertificateBuilder::profile] removes the need to
+/// hand-assemble `BasicConstraints`/`KeyUsage` through
+/// [X509CertificateBuilder::constraint_not_ca]/[X509CertificateBuilder::key_usage]
+/// for the most common certificate shapes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Profile {
+    /// A root CA: critical `BasicConstraints{cA: true}` with no path length
+    /// constraint, plus `keyCertSign | cRLSign` key usage.
+    Root,
+
+    /// A subordinate (intermediate) CA, optionally capping how many further CA
+    /// certificates may appear beneath it in the chain.
+    SubordinateCa { path_len: Option<u8> },
+
+    /// An end-entity (leaf) certificate: non-CA basic constraints plus
+    /// `digitalSignature` key usage.
+    Leaf,
+
+    /// A delegated OCSP responder certificate: non-CA basic constraints plus
+    /// `digitalSignature` key usage.
+    Ocsp,
+}
+
+impl Profile {
+    fn extensions(self) -> Vec<rfc5280::Extension> {
+        match self {
+            Self::Root => vec![
+                basic_constraints_extension(true, None),
+                key_usage_bits_extension(KeyUsages::KEY_CERT_SIGN | KeyUsages::CRL_SIGN),
+            ],
+            Self::SubordinateCa { path_len } => vec![
+                basic_constraints_extension(true, path_len.map(u32::from)),
+                key_usage_bits_extension(KeyUsages::KEY_CERT_SIGN | KeyUsages::CRL_SIGN),
+            ],
+            Self::Leaf | Self::Ocsp => vec![
+                basic_constraints_extension(false, None),
+                key_usage_bits_extension(KeyUsages::DIGITAL_SIGNATURE),
+            ],
+        }
+    }
+}
+
 /// Interface for constructing new X.509 certificates.
 ///
 /// This holds fields for various certificate metadata and allows you
@@ -886,6 +1288,9 @@ pub struct X509CertificateBuilder {
     not_before: chrono::DateTime<Utc>,
     not_after: chrono::DateTime<Utc>,
     csr_attributes: Attributes,
+    profile: Option<Profile>,
+    subject_key_identifier: Option<KeyIdGenMethod>,
+    authority_key_identifier: Option<(Bytes, KeyIdGenMethod)>,
 }
 
 impl Default for X509CertificateBuilder {
@@ -901,6 +1306,9 @@ impl Default for X509CertificateBuilder {
             not_before,
             not_after,
             csr_attributes: Attributes::default(),
+            profile: None,
+            subject_key_identifier: None,
+            authority_key_identifier: None,
         }
     }
 }
@@ -964,16 +1372,22 @@ impl X509CertificateBuilder {
         });
     }
 
-    /// Add a key usage extension.
+    /// Add a key usage extension asserting a single bit.
+    ///
+    /// Calling this more than once pushes a separate `KeyUsage` extension each
+    /// time, producing an invalid certificate. To assert multiple bits, use
+    /// [Self::key_usages] instead.
     pub fn key_usage(&mut self, key_usage: KeyUsage) {
-        let value: u8 = key_usage.into();
+        self.extensions
+            .push(key_usage_bits_extension(key_usage.into()));
+    }
 
-        self.extensions.push(rfc5280::Extension {
-            id: Oid(OID_EXTENSION_KEY_USAGE.as_ref().into()),
-            critical: Some(true),
-            // Value is a bit string. We just encode it manually since it is easy.
-            value: OctetString::new(Bytes::copy_from_slice(&[3, 2, 7, 128 | value])),
-        });
+    /// Add a key usage extension covering every bit in `usages`.
+    ///
+    /// Unlike [Self::key_usage], this combines every requested bit into a single
+    /// `KeyUsage` BIT STRING, as RFC 5280 requires.
+    pub fn key_usages(&mut self, usages: KeyUsages) {
+        self.extensions.push(key_usage_bits_extension(usages));
     }
 
     /// Add an [Attribute] to a future certificate signing requests.
@@ -984,6 +1398,158 @@ impl X509CertificateBuilder {
         self.csr_attributes.push(attribute);
     }
 
+    /// Build the PKCS#9 `extensionRequest` attribute (1.2.840.113549.1.9.14)
+    /// wrapping the extensions accumulated on this builder so far.
+    ///
+    /// Returns `None` if no extensions have been requested. Used automatically
+    /// by [Self::create_certificate_signing_request] so callers get the
+    /// extensions added to this builder (e.g. via [Self::key_usage],
+    /// [Self::constraint_not_ca] or [Self::subject_alt_names]) requested of a
+    /// CSR without having to DER-encode an `ExtensionReq` attribute by hand.
+    /// Extensions supplied by a [Profile] and queued subject/authority key
+    /// identifiers are added only when building a certificate and are not
+    /// included here.
+    fn extension_request_attribute(&self) -> Result<Option<rfc5652::Attribute>, Error> {
+        if self.extensions.is_empty() {
+            return Ok(None);
+        }
+
+        let mut extensions_der = vec![];
+        self.extensions
+            .encode_ref()
+            .write_encoded(Mode::Der, &mut extensions_der)?;
+
+        let values_der = encode_tlv(0x31, &extensions_der);
+
+        let type_oid = OID_PKCS9_EXTENSION_REQUEST.as_ref();
+        let mut attribute_der = encode_tlv(0x06, type_oid);
+        attribute_der.extend_from_slice(&values_der);
+
+        let attribute_seq = encode_tlv(0x30, &attribute_der);
+
+        let attribute = Constructed::decode(attribute_seq.as_slice(), Mode::Der, |cons| {
+            rfc5652::Attribute::take_from(cons)
+        })?;
+
+        Ok(Some(attribute))
+    }
+
+    /// Add a Subject Alternative Name extension containing the given names.
+    ///
+    /// Per RFC 5280 §4.2.1.6, the extension is marked critical automatically when
+    /// the subject DN is empty, since SAN is then the only way to identify the
+    /// certificate's subject.
+    pub fn subject_alt_names(&mut self, names: &[GeneralName]) {
+        self.extensions.push(rfc5280::Extension {
+            id: Oid(OID_EXTENSION_SUBJECT_ALT_NAME.as_ref().into()),
+            critical: Some(self.subject == Name::default()),
+            value: OctetString::new(Bytes::copy_from_slice(&encode_general_names(names))),
+        });
+    }
+
+    /// Add a Subject Alternative Name extension from DNS names, IP addresses
+    /// (v4/v6), email addresses, and URIs.
+    ///
+    /// This is a convenience wrapper around [Self::subject_alt_names] for the
+    /// common case of building a SAN extension from typed, per-kind value lists
+    /// rather than constructing [GeneralName] values directly.
+    pub fn san(&mut self, names: &SubjectAltNames) {
+        let mut general_names = Vec::with_capacity(
+            names.dns_names.len()
+                + names.ip_addresses.len()
+                + names.email_addresses.len()
+                + names.uris.len(),
+        );
+
+        general_names.extend(names.dns_names.iter().cloned().map(GeneralName::DnsName));
+        general_names.extend(names.ip_addresses.iter().map(|ip| {
+            GeneralName::IpAddress(match ip {
+                IpAddr::V4(v4) => v4.octets().to_vec(),
+                IpAddr::V6(v6) => v6.octets().to_vec(),
+            })
+        }));
+        general_names.extend(
+            names
+                .email_addresses
+                .iter()
+                .cloned()
+                .map(GeneralName::Rfc822Name),
+        );
+        general_names.extend(names.uris.iter().cloned().map(GeneralName::Uri));
+
+        self.subject_alt_names(&general_names);
+    }
+
+    /// Select a [Profile] whose extension set is auto-populated when the certificate
+    /// is built.
+    ///
+    /// Don't combine a profile with [Self::constraint_not_ca] or [Self::key_usage]:
+    /// both sources of extensions are emitted, and the result will likely be an
+    /// invalid certificate with conflicting `BasicConstraints`/`KeyUsage` values.
+    pub fn profile(&mut self, profile: Profile) {
+        self.profile = Some(profile);
+    }
+
+    /// Queue a `SubjectKeyIdentifier` extension (OID 2.5.29.14), to be computed at
+    /// build time from the certificate's own subject public key using `method`.
+    ///
+    /// Per RFC 5280 §4.2.1.2, the identifier covers the subject public key's BIT
+    /// STRING content bytes - the key material itself, excluding the ASN.1
+    /// tag/length and the unused-bits octet.
+    pub fn add_subject_key_identifier(&mut self, method: KeyIdGenMethod) {
+        self.subject_key_identifier = Some(method);
+    }
+
+    /// Queue an `AuthorityKeyIdentifier` extension (OID 2.5.29.35), wrapping
+    /// `issuer`'s computed `SubjectKeyIdentifier` in the `keyIdentifier [0]`
+    /// context field, using `method`.
+    pub fn add_authority_key_identifier_from(
+        &mut self,
+        issuer: &CapturedX509Certificate,
+        method: KeyIdGenMethod,
+    ) {
+        self.authority_key_identifier = Some((issuer.public_key_data(), method));
+    }
+
+    /// Obtain the extensions that will be encoded into the built certificate: the
+    /// manually-added extensions plus, if configured, the selected profile's
+    /// extension set and any queued key identifier extensions. `public_key_data`
+    /// is the subject public key that the certificate being built will carry,
+    /// needed to compute a queued `SubjectKeyIdentifier`.
+    fn effective_extensions(&self, public_key_data: &[u8]) -> rfc5280::Extensions {
+        let mut extensions = self.extensions.clone();
+
+        if let Some(profile) = self.profile {
+            for extension in profile.extensions() {
+                extensions.push(extension);
+            }
+        }
+
+        if let Some(method) = self.subject_key_identifier {
+            let key_id = method.generate(public_key_data);
+
+            extensions.push(rfc5280::Extension {
+                id: Oid(OID_EXTENSION_SUBJECT_KEY_IDENTIFIER.as_ref().into()),
+                critical: Some(false),
+                value: OctetString::new(Bytes::copy_from_slice(&der_octet_string(&key_id))),
+            });
+        }
+
+        if let Some((issuer_public_key_data, method)) = &self.authority_key_identifier {
+            let key_id = method.generate(issuer_public_key_data);
+
+            extensions.push(rfc5280::Extension {
+                id: Oid(OID_EXTENSION_AUTHORITY_KEY_IDENTIFIER.as_ref().into()),
+                critical: Some(false),
+                value: OctetString::new(Bytes::copy_from_slice(&der_authority_key_identifier(
+                    &key_id,
+                ))),
+            });
+        }
+
+        extensions
+    }
+
     /// Create a new certificate given settings using the provided key pair.
     pub fn create_with_key_pair(
         &self,
@@ -1016,10 +1582,13 @@ impl X509CertificateBuilder {
             },
             issuer_unique_id: None,
             subject_unique_id: None,
-            extensions: if self.extensions.is_empty() {
-                None
-            } else {
-                Some(self.extensions.clone())
+            extensions: {
+                let extensions = self.effective_extensions(&key_pair.public_key_data());
+                if extensions.is_empty() {
+                    None
+                } else {
+                    Some(extensions)
+                }
             },
             raw_data: None,
         };
@@ -1057,16 +1626,118 @@ impl X509CertificateBuilder {
         Ok((cert, key_pair))
     }
 
+    /// Create a new certificate given settings, signing it with an arbitrary [KeyInfoSigner].
+    ///
+    /// This is equivalent to [Self::create_with_key_pair] except it accepts any signer
+    /// implementing [KeyInfoSigner] rather than requiring an owned [InMemorySigningKeyPair],
+    /// making it usable with signers backed by e.g. a remote key management service.
+    pub fn create_with_signer(
+        &self,
+        signer: &dyn KeyInfoSigner,
+    ) -> Result<CapturedX509Certificate, Error> {
+        let issuer = if let Some(issuer) = &self.issuer {
+            issuer
+        } else {
+            &self.subject
+        };
+
+        let key_algorithm = signer.key_algorithm().ok_or_else(|| {
+            Error::UnknownKeyAlgorithm("OID not available due to API limitations".into())
+        })?;
+
+        let tbs_certificate = rfc5280::TbsCertificate {
+            version: Some(rfc5280::Version::V3),
+            serial_number: self.serial_number.into(),
+            signature: signer.signature_algorithm()?.into(),
+            issuer: issuer.clone(),
+            validity: rfc5280::Validity {
+                not_before: Time::from(self.not_before),
+                not_after: Time::from(self.not_after),
+            },
+            subject: self.subject.clone(),
+            subject_public_key_info: rfc5280::SubjectPublicKeyInfo {
+                algorithm: key_algorithm.into(),
+                subject_public_key: BitString::new(0, signer.public_key_data()),
+            },
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: {
+                let extensions = self.effective_extensions(&signer.public_key_data());
+                if extensions.is_empty() {
+                    None
+                } else {
+                    Some(extensions)
+                }
+            },
+            raw_data: None,
+        };
+
+        // Now encode the TBS certificate so we can sign it with the private key
+        // and include its signature.
+        let mut tbs_der = Vec::<u8>::new();
+        tbs_certificate
+            .encode_ref()
+            .write_encoded(Mode::Der, &mut tbs_der)?;
+
+        let signature = signer.try_sign(&tbs_der)?;
+        let signature_algorithm = signer.signature_algorithm()?;
+
+        let cert = rfc5280::Certificate {
+            tbs_certificate,
+            signature_algorithm: signature_algorithm.into(),
+            signature: BitString::new(0, Bytes::copy_from_slice(signature.as_ref())),
+        };
+
+        let cert = X509Certificate::from(cert);
+        let cert_der = cert.encode_der()?;
+
+        CapturedX509Certificate::from_der(cert_der)
+    }
+
+    /// Generate a self-signed certificate for the given subject and alternative names.
+    ///
+    /// The issuer is set equal to `subject` and the certificate is signed with
+    /// `key_pair`'s own private key, so `key_pair`'s public key must correspond
+    /// to the key the caller intends the certificate to vouch for. This is a
+    /// convenience wrapper around the common self-signed leaf/CA bootstrap
+    /// workflow so callers don't need a separate certificate generation crate
+    /// for basic cases.
+    pub fn generate_self_signed(
+        subject: Name,
+        alt_names: &[GeneralName],
+        key_pair: &InMemorySigningKeyPair,
+    ) -> Result<CapturedX509Certificate, Error> {
+        let mut builder = Self::default();
+        *builder.subject() = subject;
+
+        if !alt_names.is_empty() {
+            builder.subject_alt_names(alt_names);
+        }
+
+        builder.create_with_key_pair(key_pair)
+    }
+
     /// Create a new certificate signing request (CSR).
     ///
     /// The CSR is derived according to the process defined in RFC 2986 Section 3.
     /// Essentially, we collect metadata about the request, sign that metadata using
     /// a provided signing/private key, then attach the signature to form a complete
     /// certification request.
+    ///
+    /// Any extensions added to this builder (SAN, key usage, basic
+    /// constraints, ...) are carried along automatically as a PKCS#9
+    /// `extensionRequest` attribute (RFC 2985 §5.4.2), so a receiving CA can
+    /// honor them. Extensions from a [Profile] and queued key identifiers are
+    /// not carried. See [Self::extension_request_attribute].
     pub fn create_certificate_signing_request(
         &self,
         signer: &dyn KeyInfoSigner,
     ) -> Result<rfc2986::CertificationRequest, Error> {
+        let mut attributes = self.csr_attributes.clone();
+        if let Some(extension_request) = self.extension_request_attribute()? {
+            attributes.push(extension_request);
+        }
+
         let info = rfc2986::CertificationRequestInfo {
             version: rfc2986::Version::V1,
             subject: self.subject.clone(),
@@ -1081,7 +1752,7 @@ impl X509CertificateBuilder {
                     .into(),
                 subject_public_key: BitString::new(0, signer.public_key_data()),
             },
-            attributes: self.csr_attributes.clone(),
+            attributes,
         };
 
         // The signature is produced over the DER encoding of CertificationRequestInfo
@@ -1102,29 +1773,952 @@ impl X509CertificateBuilder {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use {
-        super::*,
-        crate::{EcdsaCurve, X509CertificateError},
-    };
+impl rfc2986::CertificationRequest {
+    /// Parse a certification request from DER encoded ASN.1 data.
+    pub fn from_der(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let request = Constructed::decode(data.as_ref(), Mode::Der, |cons| Self::take_from(cons))?;
 
-    #[test]
-    fn builder_ed25519_default() {
-        let builder = X509CertificateBuilder::default();
-        builder
-            .create_with_random_keypair(KeyAlgorithm::Ed25519)
-            .unwrap();
+        Ok(request)
     }
 
-    #[test]
-    fn build_ecdsa_default() {
-        for curve in EcdsaCurve::all() {
-            let key_algorithm = KeyAlgorithm::Ecdsa(*curve);
+    /// Parse a certification request from PEM encoded data.
+    ///
+    /// The data is a human readable string likely containing
+    /// `--------- BEGIN CERTIFICATE REQUEST ----------`.
+    pub fn from_pem(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let data = pem::parse(data.as_ref()).map_err(Error::PemDecode)?;
 
-            let builder = X509CertificateBuilder::default();
-            builder.create_with_random_keypair(key_algorithm).unwrap();
-        }
+        Self::from_der(data.contents())
+    }
+
+    /// DER encode this certification request.
+    pub fn encode_der(&self) -> Result<Vec<u8>, std::io::Error> {
+        let mut buffer = Vec::<u8>::new();
+        self.write_encoded(Mode::Der, &mut buffer)?;
+
+        Ok(buffer)
+    }
+
+    /// Encode this certification request to a PEM string.
+    ///
+    /// This writes a human-readable string with
+    /// `------ BEGIN CERTIFICATE REQUEST -------` armoring around the
+    /// DER-encoded request, per RFC 7468 §5.
+    pub fn encode_pem(&self) -> Result<String, std::io::Error> {
+        Ok(pem::Pem::new("CERTIFICATE REQUEST", self.encode_der()?).to_string())
+    }
+}
+
+/// Describes why building or validating a [CertificateChain] failed.
+///
+/// Each variant carries the `depth` at which the failure occurred, where
+/// depth `0` is the leaf certificate and depth increases by one for each
+/// intermediate traversed towards the trust anchor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CertificateChainError {
+    /// The certificate at `depth` was outside its validity window at the check time.
+    Expired { depth: usize },
+
+    /// No certificate in the intermediate pool was found whose subject matched
+    /// the issuer named at `depth` and whose key verified its signature.
+    IssuerNotFound { depth: usize },
+
+    /// The chain terminated without encountering a recognized trust anchor.
+    NoTrustAnchor,
+
+    /// The issuer at `depth` lacks `BasicConstraints.cA = TRUE`.
+    NotCa { depth: usize },
+
+    /// The issuer at `depth` has a `pathLenConstraint` that is violated by the
+    /// number of intermediates beneath it.
+    PathLengthExceeded { depth: usize, path_len: u32 },
+
+    /// The issuer at `depth` does not assert `keyCertSign` in its `KeyUsage` extension.
+    MissingKeyCertSign { depth: usize },
+}
+
+impl std::fmt::Display for CertificateChainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Expired { depth } => {
+                write!(f, "certificate at depth {} is outside its validity window", depth)
+            }
+            Self::IssuerNotFound { depth } => {
+                write!(f, "no issuer found for certificate at depth {}", depth)
+            }
+            Self::NoTrustAnchor => f.write_str("chain did not terminate at a trust anchor"),
+            Self::NotCa { depth } => {
+                write!(f, "issuer at depth {} is not a CA (BasicConstraints.cA != TRUE)", depth)
+            }
+            Self::PathLengthExceeded { depth, path_len } => write!(
+                f,
+                "issuer at depth {} violates its pathLenConstraint of {}",
+                depth, path_len
+            ),
+            Self::MissingKeyCertSign { depth } => write!(
+                f,
+                "issuer at depth {} lacks keyCertSign in its KeyUsage extension",
+                depth
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CertificateChainError {}
+
+/// Minimal decoded view of the `BasicConstraints` extension (2.5.29.19).
+///
+/// This is intentionally private: [X509Certificate::basic_constraints] (added
+/// alongside other typed extension accessors) is the public, general-purpose
+/// equivalent and decodes through this helper; [CertificateChain] construction
+/// uses it as well.
+fn decode_basic_constraints(value: &[u8]) -> Option<(bool, Option<u32>)> {
+    // BasicConstraints ::= SEQUENCE { cA BOOLEAN DEFAULT FALSE, pathLenConstraint INTEGER OPTIONAL }
+    if value.len() < 2 || value[0] != 0x30 {
+        return None;
+    }
+
+    let mut body = value.get(2..)?;
+    let mut ca = false;
+    let mut path_len = None;
+
+    if body.first() == Some(&0x01) {
+        // BOOLEAN tag.
+        ca = *body.get(2)? != 0x00;
+        body = body.get(3..)?;
+    }
+
+    if body.first() == Some(&0x02) {
+        // INTEGER tag.
+        let len = *body.get(1)? as usize;
+        let int_bytes = body.get(2..2 + len)?;
+
+        let mut v: u32 = 0;
+        for b in int_bytes {
+            v = (v << 8) | *b as u32;
+        }
+        path_len = Some(v);
+    }
+
+    Some((ca, path_len))
+}
+
+/// Whether a `KeyUsage` extension (2.5.29.15) asserts the `keyCertSign` bit.
+///
+/// Like [decode_basic_constraints], this is a private helper used only by
+/// [CertificateChain]; see [X509Certificate::key_usage] for the public,
+/// general-purpose accessor.
+fn key_usage_asserts_key_cert_sign(value: &[u8]) -> bool {
+    // KeyUsage ::= BIT STRING. DER-encoded as `03 <len> <unused-bits> <octets...>`.
+    // keyCertSign is bit 5, i.e. mask 0x04 of the first content octet.
+    if value.len() < 4 || value[0] != 0x03 {
+        return false;
+    }
+
+    value[3] & 0x04 != 0
+}
+
+/// Verification material for a trust anchor, independent of how it was expressed.
+///
+/// A trust anchor is most commonly a full CA certificate, but RFC 5914 / RFC 5937
+/// also permit expressing one as a bare subject `Name` + `SubjectPublicKeyInfo`,
+/// carrying no validity window or self-signature of its own. [CertificateChain::build]
+/// accepts either form, which lets callers pin trust to a key rather than to a
+/// whole certificate.
+#[derive(Clone, Debug)]
+pub enum TrustAnchor {
+    /// A full certificate. Subject to the same validity/`BasicConstraints`/`KeyUsage`
+    /// checks as any other issuer in the chain.
+    Certificate(CapturedX509Certificate),
+
+    /// A bare subject name and public key, with no validity window of its own.
+    NameAndKey {
+        subject: Name,
+        public_key_data: Bytes,
+    },
+}
+
+impl TrustAnchor {
+    /// Construct an anchor backed by a full certificate, extracting its SPKI.
+    pub fn from_certificate(cert: &CapturedX509Certificate) -> Self {
+        Self::Certificate(cert.clone())
+    }
+
+    /// Construct a bare name+key anchor, carrying no validity window of its own.
+    pub fn from_name_and_key(subject: Name, public_key_data: impl Into<Bytes>) -> Self {
+        Self::NameAndKey {
+            subject,
+            public_key_data: public_key_data.into(),
+        }
+    }
+
+    /// Obtain the anchor's subject name.
+    pub fn subject_name(&self) -> &Name {
+        match self {
+            Self::Certificate(cert) => cert.subject_name(),
+            Self::NameAndKey { subject, .. } => subject,
+        }
+    }
+
+    /// Obtain the raw public key data, usable with
+    /// [CapturedX509Certificate::verify_signed_by_public_key].
+    pub fn public_key_data(&self) -> Bytes {
+        match self {
+            Self::Certificate(cert) => cert.public_key_data(),
+            Self::NameAndKey {
+                public_key_data, ..
+            } => public_key_data.clone(),
+        }
+    }
+
+    /// Obtain the backing certificate, if this anchor was constructed from one.
+    pub fn certificate(&self) -> Option<&CapturedX509Certificate> {
+        match self {
+            Self::Certificate(cert) => Some(cert),
+            Self::NameAndKey { .. } => None,
+        }
+    }
+}
+
+/// A validated certificate path from a leaf certificate to a trust anchor.
+///
+/// Instances are constructed via [Self::build], which walks the issuer chain
+/// starting at a leaf certificate and validates each link according to a
+/// subset of the rules in RFC 5280 §6.1.
+#[derive(Clone, Debug)]
+pub struct CertificateChain {
+    /// The leaf certificate followed by each successive issuer certificate actually
+    /// traversed en route to the trust anchor. Does not include the anchor itself
+    /// when it terminates on a bare [TrustAnchor::NameAndKey], since that form has
+    /// no certificate to include.
+    chain: Vec<CapturedX509Certificate>,
+
+    /// The trust anchor this chain terminates at.
+    anchor: TrustAnchor,
+}
+
+impl CertificateChain {
+    /// Build and validate a certificate path from `leaf` to one of `trust_anchors`.
+    ///
+    /// `intermediates` is a pool of candidate issuer certificates consulted when
+    /// walking the chain upwards. `time` is the instant at which each certificate's
+    /// validity window is evaluated.
+    ///
+    /// For each link, this enforces that the issuer's `BasicConstraints` extension
+    /// asserts `cA = TRUE` (with any `pathLenConstraint` respected), and that the
+    /// issuer's `KeyUsage` extension asserts `keyCertSign`. The self-signed anchor
+    /// case (subject == issuer) is treated as a terminal node. A [TrustAnchor]
+    /// expressed as a bare name+key skips its own validity/self-signature checks,
+    /// since it carries no validity window of its own; its key is still used to
+    /// verify the last link in the chain.
+    pub fn build(
+        leaf: &CapturedX509Certificate,
+        intermediates: &[CapturedX509Certificate],
+        trust_anchors: &[TrustAnchor],
+        time: DateTime<Utc>,
+    ) -> Result<Self, CertificateChainError> {
+        let mut chain = vec![leaf.clone()];
+        let mut current = leaf.clone();
+        let mut depth = 0usize;
+
+        loop {
+            if !current.time_constraints_valid(Some(time)) {
+                return Err(CertificateChainError::Expired { depth });
+            }
+
+            if let Some(anchor) = trust_anchors.iter().find(|anchor| {
+                current
+                    .verify_signed_by_public_key(anchor.public_key_data())
+                    .is_ok()
+            }) {
+                if let TrustAnchor::Certificate(anchor_cert) = anchor {
+                    if anchor_cert != &current {
+                        if !anchor_cert.time_constraints_valid(Some(time)) {
+                            return Err(CertificateChainError::Expired { depth: depth + 1 });
+                        }
+                        Self::validate_issuer(anchor_cert, depth)?;
+                        chain.push(anchor_cert.clone());
+                    }
+                }
+
+                return Ok(Self {
+                    chain,
+                    anchor: anchor.clone(),
+                });
+            }
+
+            if current.subject_is_issuer() {
+                // Self-signed but not one of the recognized trust anchors: untrusted root.
+                return Err(CertificateChainError::NoTrustAnchor);
+            }
+
+            let issuer = intermediates
+                .iter()
+                .find(|candidate| {
+                    candidate.subject_name() == current.issuer_name()
+                        && current.verify_signed_by_certificate(*candidate).is_ok()
+                })
+                .ok_or(CertificateChainError::IssuerNotFound { depth })?;
+
+            Self::validate_issuer(issuer, depth)?;
+
+            chain.push(issuer.clone());
+            current = issuer.clone();
+            depth += 1;
+        }
+    }
+
+    /// Validate that `issuer`, found at `depth` intermediates below it, is allowed to sign.
+    fn validate_issuer(
+        issuer: &CapturedX509Certificate,
+        depth: usize,
+    ) -> Result<(), CertificateChainError> {
+        let extension = issuer
+            .iter_extensions()
+            .find(|ext| ext.id == OID_EXTENSION_BASIC_CONSTRAINTS)
+            .ok_or(CertificateChainError::NotCa { depth })?;
+
+        let (ca, path_len) = decode_basic_constraints(&extension.value.to_bytes())
+            .ok_or(CertificateChainError::NotCa { depth })?;
+
+        if !ca {
+            return Err(CertificateChainError::NotCa { depth });
+        }
+
+        if let Some(path_len) = path_len {
+            if depth as u32 > path_len {
+                return Err(CertificateChainError::PathLengthExceeded { depth, path_len });
+            }
+        }
+
+        let key_usage_ok = issuer
+            .iter_extensions()
+            .find(|ext| ext.id == OID_EXTENSION_KEY_USAGE)
+            .map(|ext| key_usage_asserts_key_cert_sign(&ext.value.to_bytes()))
+            .unwrap_or(false);
+
+        if !key_usage_ok {
+            return Err(CertificateChainError::MissingKeyCertSign { depth });
+        }
+
+        Ok(())
+    }
+
+    /// Obtain the certificates constituting this chain, leaf first.
+    pub fn certificates(&self) -> &[CapturedX509Certificate] {
+        &self.chain
+    }
+
+    /// Obtain the leaf certificate this chain was built from.
+    pub fn leaf(&self) -> &CapturedX509Certificate {
+        &self.chain[0]
+    }
+
+    /// Obtain the trust anchor this chain terminates at.
+    pub fn trust_anchor(&self) -> &TrustAnchor {
+        &self.anchor
+    }
+}
+
+/// Why a certificate's revocation status could not be established against a CRL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RevocationCheckError {
+    /// The CRL's signature did not verify against the supplied issuer public key.
+    SignatureVerificationFailed,
+
+    /// The CRL's issuer does not match the certificate's issuer.
+    IssuerMismatch,
+
+    /// `time` fell outside the CRL's `thisUpdate`/`nextUpdate` window.
+    CrlNotCurrent,
+}
+
+impl std::fmt::Display for RevocationCheckError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SignatureVerificationFailed => f.write_str("CRL signature verification failed"),
+            Self::IssuerMismatch => f.write_str("CRL issuer does not match certificate issuer"),
+            Self::CrlNotCurrent => f.write_str("CRL is not current at the requested time"),
+        }
+    }
+}
+
+impl std::error::Error for RevocationCheckError {}
+
+/// A single entry in a [CertificateRevocationList].
+#[derive(Clone, Debug)]
+pub struct RevokedCertificate {
+    /// The serial number of the revoked certificate.
+    pub serial_number: Integer,
+
+    /// The time at which the certificate was revoked.
+    pub revocation_date: DateTime<Utc>,
+
+    /// The CRL reason code (RFC 5280 §5.3.1), if the entry carries a reason code extension.
+    pub reason_code: Option<u8>,
+}
+
+/// Provides a high-level interface to an RFC 5280 `CertificateList` (a CRL).
+///
+/// This mirrors [X509Certificate] in spirit: it wraps the raw ASN.1 structure
+/// and exposes ergonomic accessors for the fields callers care about when
+/// performing revocation checks.
+#[derive(Clone, Debug)]
+pub struct CertificateRevocationList(rfc5280::CertificateList);
+
+impl CertificateRevocationList {
+    /// Construct an instance by parsing DER encoded ASN.1 data.
+    pub fn from_der(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let crl = Constructed::decode(data.as_ref(), Mode::Der, |cons| {
+            rfc5280::CertificateList::take_from(cons)
+        })?;
+
+        Ok(Self(crl))
+    }
+
+    /// Construct an instance by parsing PEM encoded ASN.1 data.
+    ///
+    /// The data is a human readable string likely containing
+    /// `--------- BEGIN X509 CRL ----------`.
+    pub fn from_pem(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let data = pem::parse(data.as_ref()).map_err(Error::PemDecode)?;
+
+        Self::from_der(data.contents())
+    }
+
+    /// Obtain the issuer of this CRL.
+    pub fn issuer_name(&self) -> &Name {
+        &self.0.tbs_cert_list.issuer
+    }
+
+    /// Obtain the time this CRL was issued.
+    pub fn this_update(&self) -> DateTime<Utc> {
+        self.0.tbs_cert_list.this_update.clone().into()
+    }
+
+    /// Obtain the time by which the next CRL is expected to be issued, if declared.
+    pub fn next_update(&self) -> Option<DateTime<Utc>> {
+        self.0
+            .tbs_cert_list
+            .next_update
+            .clone()
+            .map(Into::into)
+    }
+
+    /// Whether `time` falls within this CRL's validity window.
+    ///
+    /// If `next_update` was not declared, the CRL is considered current as long
+    /// as `time` is not before `this_update`.
+    pub fn is_current(&self, time: DateTime<Utc>) -> bool {
+        time >= self.this_update()
+            && self.next_update().map(|next| time <= next).unwrap_or(true)
+    }
+
+    /// Iterate over the certificates revoked by this CRL.
+    pub fn iter_revoked_certificates(&self) -> impl Iterator<Item = RevokedCertificate> + '_ {
+        self.0.tbs_cert_list.iter_revoked_certificates().map(|entry| RevokedCertificate {
+            serial_number: entry.user_certificate.clone(),
+            revocation_date: entry.revocation_date.clone().into(),
+            reason_code: entry.reason_code(),
+        })
+    }
+
+    /// Verify this CRL's signature using the issuer's raw public key data.
+    ///
+    /// This is the CRL analog of [CapturedX509Certificate::verify_signed_by_public_key].
+    /// `issuer_key_algorithm` must describe the key behind `public_key_data`, since
+    /// a bare CRL (unlike a certificate) carries no `SubjectPublicKeyInfo` of its own.
+    pub fn verify_signed_by_public_key(
+        &self,
+        public_key_data: impl AsRef<[u8]>,
+        issuer_key_algorithm: KeyAlgorithm,
+    ) -> Result<(), Error> {
+        let signed_data = self
+            .0
+            .tbs_cert_list
+            .raw_data
+            .as_ref()
+            .expect("CRL should retain raw TBS data for signature verification");
+        let signature = self.0.signature.octet_bytes();
+
+        let signature_algorithm = SignatureAlgorithm::try_from(&self.0.signature_algorithm)?;
+        let verify_algorithm =
+            signature_algorithm.resolve_verification_algorithm(issuer_key_algorithm)?;
+
+        verify_algorithm.verify(public_key_data.as_ref(), signed_data, &signature)
+    }
+}
+
+impl CapturedX509Certificate {
+    /// Test whether this certificate is listed as revoked on `crl`.
+    ///
+    /// This first verifies the CRL's signature using this certificate's issuer
+    /// public key (so `self` should be the certificate whose issuer produced
+    /// `crl`), confirms the CRL issuer matches this certificate's issuer, checks
+    /// that the CRL is current at `time`, and finally compares
+    /// [X509Certificate::serial_number_asn1] against the CRL's revoked entries.
+    pub fn is_revoked_by(
+        &self,
+        crl: &CertificateRevocationList,
+        issuer_public_key_data: impl AsRef<[u8]>,
+        issuer_key_algorithm: KeyAlgorithm,
+        time: DateTime<Utc>,
+    ) -> Result<bool, RevocationCheckError> {
+        crl.verify_signed_by_public_key(issuer_public_key_data, issuer_key_algorithm)
+            .map_err(|_| RevocationCheckError::SignatureVerificationFailed)?;
+
+        if crl.issuer_name() != self.issuer_name() {
+            return Err(RevocationCheckError::IssuerMismatch);
+        }
+
+        if !crl.is_current(time) {
+            return Err(RevocationCheckError::CrlNotCurrent);
+        }
+
+        Ok(crl
+            .iter_revoked_certificates()
+            .any(|entry| &entry.serial_number == self.serial_number_asn1()))
+    }
+
+    /// Validate the full certificate path from `self` to one of `trust_anchors`,
+    /// reporting the outcome of each RFC 5280 §6.1 constraint at every link rather
+    /// than stopping at the first failure.
+    ///
+    /// This walks the same issuer links as [Self::resolve_signing_chain] and
+    /// [CertificateChain::build], but where those either give up on failure or
+    /// stop at the first unmet constraint, this records every check's outcome
+    /// for every certificate it manages to reach, so a caller can see exactly
+    /// which constraint failed and where. The walk still stops once no issuer
+    /// can be found for the current certificate, since there is nothing further
+    /// to check beyond that point.
+    pub fn validate_chain(
+        &self,
+        intermediates: &[CapturedX509Certificate],
+        trust_anchors: &[TrustAnchor],
+        verification_time: DateTime<Utc>,
+    ) -> ChainValidationReport {
+        let mut certificates = vec![];
+        let mut current = self.clone();
+        let mut depth = 0usize;
+        let mut terminated_at_trust_anchor = false;
+
+        loop {
+            let within_validity_window = current.time_constraints_valid(Some(verification_time));
+
+            if let Some(anchor) = trust_anchors.iter().find(|anchor| {
+                current
+                    .verify_signed_by_public_key(anchor.public_key_data())
+                    .is_ok()
+            }) {
+                let issuer_name_matches = current.issuer_name() == anchor.subject_name();
+
+                let (issuer_is_ca, path_len_respected, issuer_asserts_key_cert_sign) =
+                    match anchor.certificate() {
+                        Some(anchor_cert) if anchor_cert != &current => {
+                            Self::issuer_constraint_report(anchor_cert, depth)
+                        }
+                        _ => (None, None, None),
+                    };
+
+                certificates.push(CertificateValidationReport {
+                    depth,
+                    within_validity_window,
+                    issuer_name_matches,
+                    is_trust_anchor: true,
+                    issuer_is_ca,
+                    path_len_respected,
+                    issuer_asserts_key_cert_sign,
+                });
+
+                terminated_at_trust_anchor = true;
+                break;
+            }
+
+            // Self-signed but not a recognized trust anchor (the trust_anchors
+            // search above already failed this iteration): there's no further
+            // issuer to find, so don't let the search below match `current`
+            // against itself - `build` makes this same check for the same
+            // reason, and skipping it here left the walk matching a self-signed
+            // intermediate as its own issuer forever.
+            let issuer = if current.subject_is_issuer() {
+                None
+            } else {
+                intermediates.iter().find(|candidate| {
+                    candidate.subject_name() == current.issuer_name()
+                        && current.verify_signed_by_certificate(*candidate).is_ok()
+                })
+            };
+
+            let (issuer_is_ca, path_len_respected, issuer_asserts_key_cert_sign) = issuer
+                .map(|issuer| Self::issuer_constraint_report(issuer, depth))
+                .unwrap_or((None, None, None));
+
+            certificates.push(CertificateValidationReport {
+                depth,
+                within_validity_window,
+                issuer_name_matches: issuer.is_some(),
+                is_trust_anchor: false,
+                issuer_is_ca,
+                path_len_respected,
+                issuer_asserts_key_cert_sign,
+            });
+
+            match issuer {
+                Some(issuer) => {
+                    current = issuer.clone();
+                    depth += 1;
+                }
+                None => break,
+            }
+        }
+
+        ChainValidationReport {
+            certificates,
+            terminated_at_trust_anchor,
+        }
+    }
+
+    /// Evaluate `issuer`'s `BasicConstraints`/`KeyUsage` against `depth`, using the
+    /// public typed accessors rather than re-parsing extensions by hand.
+    fn issuer_constraint_report(
+        issuer: &CapturedX509Certificate,
+        depth: usize,
+    ) -> (Option<bool>, Option<bool>, Option<bool>) {
+        let basic_constraints = issuer.basic_constraints();
+
+        let issuer_is_ca = Some(basic_constraints.as_ref().is_some_and(|bc| bc.ca));
+
+        let path_len_respected = basic_constraints
+            .as_ref()
+            .and_then(|bc| bc.path_len)
+            .map(|path_len| depth as u32 <= path_len);
+
+        let issuer_asserts_key_cert_sign =
+            Some(issuer.key_usage().is_some_and(|ku| ku.key_cert_sign));
+
+        (issuer_is_ca, path_len_respected, issuer_asserts_key_cert_sign)
+    }
+}
+
+/// The outcome of validating a single certificate's position in a certificate path.
+///
+/// `Option<bool>` fields are `None` when the underlying check doesn't apply (e.g.
+/// the chain terminated on a bare [TrustAnchor::NameAndKey], which carries no
+/// `BasicConstraints`/`KeyUsage` of its own to check).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateValidationReport {
+    /// How many intermediates separate this certificate from the leaf.
+    pub depth: usize,
+
+    /// Whether `verification_time` fell within this certificate's validity window.
+    pub within_validity_window: bool,
+
+    /// Whether an issuer (certificate or trust anchor) matching this certificate's
+    /// `issuer` name, and verifying its signature, was found.
+    pub issuer_name_matches: bool,
+
+    /// Whether this link terminated the chain at a trust anchor.
+    pub is_trust_anchor: bool,
+
+    /// Whether the issuer's `BasicConstraints` extension asserts `cA = TRUE`.
+    pub issuer_is_ca: Option<bool>,
+
+    /// Whether the issuer's `pathLenConstraint`, if any, is respected at this depth.
+    pub path_len_respected: Option<bool>,
+
+    /// Whether the issuer's `KeyUsage` extension asserts `keyCertSign`.
+    pub issuer_asserts_key_cert_sign: Option<bool>,
+}
+
+impl CertificateValidationReport {
+    /// Whether every constraint checked for this certificate passed.
+    pub fn is_valid(&self) -> bool {
+        self.within_validity_window
+            && self.issuer_name_matches
+            && self.issuer_is_ca.unwrap_or(self.is_trust_anchor)
+            && self.path_len_respected.unwrap_or(true)
+            && self.issuer_asserts_key_cert_sign.unwrap_or(self.is_trust_anchor)
+    }
+}
+
+/// A detailed, per-certificate report produced by [CapturedX509Certificate::validate_chain].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainValidationReport {
+    /// One entry per certificate actually reached, starting at the leaf (depth 0).
+    pub certificates: Vec<CertificateValidationReport>,
+
+    /// Whether the walk terminated at a recognized trust anchor, as opposed to
+    /// running out of candidate issuers first.
+    pub terminated_at_trust_anchor: bool,
+}
+
+impl ChainValidationReport {
+    /// Whether the chain terminated at a trust anchor and every certificate along
+    /// the way passed every constraint checked for it.
+    pub fn is_valid(&self) -> bool {
+        self.terminated_at_trust_anchor && self.certificates.iter().all(|c| c.is_valid())
+    }
+}
+
+/// Encode a DER length per X.690 §8.1.3: short-form for content under 128
+/// bytes, long-form (minimal-length big-endian octets, itself prefixed by a
+/// length-of-length byte with the high bit set) otherwise.
+fn encode_der_length(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        return vec![len as u8];
+    }
+
+    let len_bytes = len.to_be_bytes();
+    let significant = {
+        let first_nonzero = len_bytes.iter().position(|&b| b != 0).unwrap_or(len_bytes.len() - 1);
+        &len_bytes[first_nonzero..]
+    };
+
+    let mut out = Vec::with_capacity(1 + significant.len());
+    out.push(0x80 | significant.len() as u8);
+    out.extend_from_slice(significant);
+    out
+}
+
+/// Encode a single DER TLV: `tag`, `content`'s length (short- or long-form
+/// per [encode_der_length]), then `content` itself.
+fn encode_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + 4 + content.len());
+    out.push(tag);
+    out.extend_from_slice(&encode_der_length(content.len()));
+    out.extend_from_slice(content);
+    out
+}
+
+/// Parse a single DER TLV, returning its tag, content bytes, and the unconsumed remainder.
+///
+/// Supports both short-form and long-form (up to 4 length octets) DER lengths.
+fn parse_tlv(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let tag = *data.first()?;
+    let len_byte = *data.get(1)?;
+
+    let (len, header_len) = if len_byte & 0x80 == 0 {
+        (len_byte as usize, 2usize)
+    } else {
+        let octets = (len_byte & 0x7f) as usize;
+        if octets == 0 || octets > 4 {
+            return None;
+        }
+
+        let mut len = 0usize;
+        for i in 0..octets {
+            len = (len << 8) | *data.get(2 + i)? as usize;
+        }
+
+        (len, 2 + octets)
+    };
+
+    let content = data.get(header_len..header_len + len)?;
+    let rest = data.get(header_len + len..)?;
+
+    Some((tag, content, rest))
+}
+
+/// A cheap, unparsed handle to a single certificate within a larger bundle.
+///
+/// Obtained from [RawCertParser]. Exposes just the raw DER slice plus
+/// cheaply-extractable selectors - the serial number and the issuer/subject
+/// `Name` DER - without fully decoding the `TbsCertificate`. Call [Self::parse]
+/// or [Self::into_captured] once a candidate has been selected.
+#[derive(Clone, Debug)]
+pub struct RawX509Certificate {
+    der: Bytes,
+}
+
+impl RawX509Certificate {
+    /// Obtain the raw DER bytes of this certificate.
+    pub fn der_data(&self) -> &Bytes {
+        &self.der
+    }
+
+    /// Fully parse this certificate.
+    pub fn parse(&self) -> Result<X509Certificate, Error> {
+        X509Certificate::from_der(&self.der)
+    }
+
+    /// Fully parse this certificate, retaining its original DER bytes.
+    pub fn into_captured(self) -> Result<CapturedX509Certificate, Error> {
+        CapturedX509Certificate::from_der(self.der.to_vec())
+    }
+
+    /// Cheaply extract the content bytes of the `serialNumber` `INTEGER`.
+    ///
+    /// Returns `None` if the DER doesn't parse as far as the serial number.
+    pub fn serial_number_bytes(&self) -> Option<&[u8]> {
+        let (_, content, _) = self.tbs_certificate_field(1)?;
+        Some(content)
+    }
+
+    /// Cheaply extract the raw DER (tag+length+content) of the issuer `Name`.
+    pub fn issuer_name_der(&self) -> Option<&[u8]> {
+        self.tbs_certificate_field_der(3)
+    }
+
+    /// Cheaply extract the raw DER (tag+length+content) of the subject `Name`.
+    pub fn subject_name_der(&self) -> Option<&[u8]> {
+        self.tbs_certificate_field_der(5)
+    }
+
+    /// Walk `TbsCertificate` fields positionally (skipping the optional `[0]` version
+    /// tag) and return the `index`-th field's (tag, content, remainder after it), where
+    /// 1 = serialNumber, 2 = signature AlgorithmIdentifier, 3 = issuer, 4 = validity,
+    /// 5 = subject.
+    fn tbs_certificate_field(&self, index: usize) -> Option<(u8, &[u8], &[u8])> {
+        let (_, cert_body, _) = parse_tlv(&self.der)?;
+        let (_, tbs_body, _) = parse_tlv(cert_body)?;
+
+        let mut rest = tbs_body;
+        // Skip an explicit [0] version tag, if present; it isn't counted in `index`.
+        if rest.first() == Some(&0xa0) {
+            let (_, _, after) = parse_tlv(rest)?;
+            rest = after;
+        }
+
+        let mut field = None;
+        for _ in 0..index {
+            let (tag, content, after) = parse_tlv(rest)?;
+            field = Some((tag, content, after));
+            rest = after;
+        }
+
+        field
+    }
+
+    fn tbs_certificate_field_der(&self, index: usize) -> Option<&[u8]> {
+        let (_, cert_body, _) = parse_tlv(&self.der)?;
+        let (_, tbs_body, _) = parse_tlv(cert_body)?;
+
+        let mut rest = tbs_body;
+        if rest.first() == Some(&0xa0) {
+            let (_, _, after) = parse_tlv(rest)?;
+            rest = after;
+        }
+
+        for _ in 0..index - 1 {
+            let (_, _, after) = parse_tlv(rest)?;
+            rest = after;
+        }
+
+        let field_start = rest;
+        let (_, _, after) = parse_tlv(field_start)?;
+        let field_len = field_start.len() - after.len();
+
+        field_start.get(..field_len)
+    }
+}
+
+/// A cheap, streaming splitter over a bundle of concatenated X.509 certificates.
+///
+/// Unlike [X509Certificate::from_pem_multiple], this does not fully decode each
+/// certificate's `TbsCertificate` up front. It scans the bundle - either
+/// PEM-armored or raw concatenated DER - and yields lightweight [RawX509Certificate]
+/// handles exposing only cheaply-extractable selectors, so a caller can filter
+/// candidates before paying for a full parse. This turns bundle ingestion from
+/// O(n) full parses into O(n) cheap splits plus O(k) full parses.
+pub struct RawCertParser<'a> {
+    remaining: &'a [u8],
+    pem_tags: &'a [&'a str],
+}
+
+impl<'a> RawCertParser<'a> {
+    /// Construct a parser over PEM-armored data, accepting only `CERTIFICATE` tags.
+    ///
+    /// Like [X509Certificate::from_pem_multiple], interleaved unknown `BEGIN <tag>`
+    /// records are silently skipped.
+    pub fn new_pem(data: &'a [u8]) -> Self {
+        Self::new_pem_tags(data, &["CERTIFICATE"])
+    }
+
+    /// Construct a PEM parser with a caller-chosen set of acceptable tags.
+    pub fn new_pem_tags(data: &'a [u8], pem_tags: &'a [&'a str]) -> Self {
+        Self {
+            remaining: data,
+            pem_tags,
+        }
+    }
+
+    /// Construct a parser over a bundle of back-to-back raw DER certificates.
+    pub fn new_der(data: &'a [u8]) -> Self {
+        Self {
+            remaining: data,
+            pem_tags: &[],
+        }
+    }
+}
+
+impl<'a> Iterator for RawCertParser<'a> {
+    type Item = RawX509Certificate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pem_tags.is_empty() {
+            let (_, _, rest) = parse_tlv(self.remaining)?;
+            let consumed = self.remaining.len() - rest.len();
+            let der = Bytes::copy_from_slice(&self.remaining[..consumed]);
+            self.remaining = rest;
+
+            Some(RawX509Certificate { der })
+        } else {
+            loop {
+                let text = std::str::from_utf8(self.remaining).ok()?;
+                let begin = text.find("-----BEGIN ")?;
+                let tag_start = begin + "-----BEGIN ".len();
+                let tag_end = tag_start + text[tag_start..].find("-----")?;
+                let tag = &text[tag_start..tag_end];
+
+                let footer = format!("-----END {}-----", tag);
+                let body_start = tag_end + "-----".len();
+                let footer_pos = body_start + text[body_start..].find(&footer)?;
+                let end_pos = footer_pos + footer.len();
+
+                let record = &self.remaining[begin..end_pos];
+                self.remaining = &self.remaining[end_pos..];
+
+                if self.pem_tags.contains(&tag) {
+                    let parsed = pem::parse(record).ok()?;
+                    return Some(RawX509Certificate {
+                        der: Bytes::copy_from_slice(parsed.contents()),
+                    });
+                }
+                // Unknown tag: skip and keep scanning for the next record.
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{EcdsaCurve, X509CertificateError},
+    };
+
+    #[test]
+    fn builder_ed25519_default() {
+        let builder = X509CertificateBuilder::default();
+        builder
+            .create_with_random_keypair(KeyAlgorithm::Ed25519)
+            .unwrap();
+    }
+
+    #[test]
+    fn build_ecdsa_default() {
+        for curve in EcdsaCurve::all() {
+            // ring has no secp256k1 signing support, so a key pair can't be
+            // generated for it; it is verification-only.
+            if *curve == EcdsaCurve::Secp256k1 {
+                continue;
+            }
+
+            let key_algorithm = KeyAlgorithm::Ecdsa(*curve);
+
+            let builder = X509CertificateBuilder::default();
+            builder.create_with_random_keypair(key_algorithm).unwrap();
+        }
     }
 
     #[test]
@@ -1147,6 +2741,12 @@ mod test {
     #[test]
     fn builder_csr_ecdsa() -> Result<(), Error> {
         for curve in EcdsaCurve::all() {
+            // ring has no secp256k1 signing support, so a key pair can't be
+            // generated for it; it is verification-only.
+            if *curve == EcdsaCurve::Secp256k1 {
+                continue;
+            }
+
             let key_algorithm = KeyAlgorithm::Ecdsa(*curve);
 
             let key = InMemorySigningKeyPair::generate_random(key_algorithm)?;
@@ -1213,4 +2813,53 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn validate_chain_self_signed_non_anchor_terminates() -> Result<(), Error> {
+        let key_pair = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519)?;
+        let cert = X509CertificateBuilder::generate_self_signed(Name::default(), &[], &key_pair)?;
+
+        // `cert` is its own issuer and is not among `trust_anchors`, so the
+        // walk has to recognize `subject_is_issuer()` and stop rather than
+        // keep matching `cert` against itself as its own issuer forever.
+        let report = cert.validate_chain(&[cert.clone()], &[], Utc::now());
+
+        assert!(!report.terminated_at_trust_anchor);
+        assert_eq!(report.certificates.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn encode_general_names_long_form_length_roundtrips() {
+        // An ordinary 200-byte DNS name: well past the 127-byte short-form
+        // cutoff, so both the name's own length and the enclosing SEQUENCE's
+        // length need a long-form encoding.
+        let long_name = "a".repeat(200);
+        let names = vec![GeneralName::DnsName(long_name.clone())];
+
+        let der = encode_general_names(&names);
+        assert!(der[1] & 0x80 != 0, "expected a long-form SEQUENCE length");
+
+        let decoded = decode_general_names(&der).unwrap();
+        assert_eq!(decoded, vec![GeneralName::DnsName(long_name)]);
+    }
+
+    #[test]
+    fn csr_with_large_extension_set_succeeds() -> Result<(), Error> {
+        let key = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519)?;
+        let mut builder = X509CertificateBuilder::default();
+
+        // Enough SAN entries to push the DER-encoded `ExtensionReq` attribute
+        // past the 127-byte short-form length cutoff, so building the CSR
+        // below exercises `extension_request_attribute`'s long-form lengths.
+        let names: Vec<GeneralName> = (0..10)
+            .map(|i| GeneralName::DnsName(format!("host-{i}.example.com")))
+            .collect();
+        builder.subject_alt_names(&names);
+
+        builder.create_certificate_signing_request(&key)?;
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@
 use {
     crate::{
         rfc3447::RsaPrivateKey, rfc5958::OneAsymmetricKey, EcdsaCurve, KeyAlgorithm,
-        SignatureAlgorithm, X509CertificateError as Error,
+        SignatureAlgorithm, VerificationAlgorithm, X509CertificateError as Error,
     },
     bcder::decode::Constructed,
     bytes::Bytes,
@@ -57,6 +57,83 @@ pub trait Sign {
 /// A superset of [Signer] and [Sign].
 pub trait KeyInfoSigner: Signer<Signature> + Sign {}
 
+/// A key pair whose private key material never enters this process.
+///
+/// Implement this to delegate signing to an external device — a cloud KMS, a
+/// PKCS#11 token, a TPM — that only ever hands back signatures, never key bytes.
+/// Wrap an implementation in [RemoteSigningKeyPair] to obtain a [KeyInfoSigner]
+/// that can issue and sign X.509 certificates like any in-memory key pair.
+pub trait RemoteKeyPair {
+    /// Obtain the algorithm of the remote key, if it can be determined.
+    fn key_algorithm(&self) -> Option<KeyAlgorithm>;
+
+    /// Obtain the raw bytes constituting the public key.
+    fn public_key_data(&self) -> Bytes;
+
+    /// Obtain the [SignatureAlgorithm] that this key pair signs with.
+    fn signature_algorithm(&self) -> Result<SignatureAlgorithm, Error>;
+
+    /// Sign `msg`, forwarding the request to the remote device.
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Error>;
+}
+
+/// Adapts a [RemoteKeyPair] to [KeyInfoSigner].
+///
+/// The key-material accessors [Sign] requires (`private_key_data`, `rsa_primes`)
+/// always return `None`/`Ok(None)`, since a [RemoteKeyPair]'s whole purpose is to
+/// keep that data off this process.
+pub struct RemoteSigningKeyPair<T: RemoteKeyPair>(T);
+
+impl<T: RemoteKeyPair> RemoteSigningKeyPair<T> {
+    /// Wrap a [RemoteKeyPair] so it can be used as a [KeyInfoSigner].
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Obtain a reference to the wrapped [RemoteKeyPair].
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: RemoteKeyPair> Signer<Signature> for RemoteSigningKeyPair<T> {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, signature::Error> {
+        Ok(Signature::from(
+            self.0.sign(msg).map_err(|_| signature::Error::new())?,
+        ))
+    }
+}
+
+impl<T: RemoteKeyPair> Sign for RemoteSigningKeyPair<T> {
+    fn sign(&self, message: &[u8]) -> Result<(Vec<u8>, SignatureAlgorithm), Error> {
+        let algorithm = self.0.signature_algorithm()?;
+
+        Ok((self.0.sign(message)?, algorithm))
+    }
+
+    fn key_algorithm(&self) -> Option<KeyAlgorithm> {
+        self.0.key_algorithm()
+    }
+
+    fn public_key_data(&self) -> Bytes {
+        self.0.public_key_data()
+    }
+
+    fn signature_algorithm(&self) -> Result<SignatureAlgorithm, Error> {
+        self.0.signature_algorithm()
+    }
+
+    fn private_key_data(&self) -> Option<Zeroizing<Vec<u8>>> {
+        None
+    }
+
+    fn rsa_primes(&self) -> Result<Option<(Zeroizing<Vec<u8>>, Zeroizing<Vec<u8>>)>, Error> {
+        Ok(None)
+    }
+}
+
+impl<T: RemoteKeyPair> KeyInfoSigner for RemoteSigningKeyPair<T> {}
+
 #[derive(Clone, Debug)]
 pub struct Signature(Vec<u8>);
 
@@ -112,12 +189,72 @@ pub struct Ed25519KeyPair {
     ring_pair: ringsig::Ed25519KeyPair,
 }
 
+/// The padding scheme and digest algorithm an [RsaKeyPair] signs with.
+///
+/// `ring` requires choosing one of these upfront via a `&dyn RsaEncoding`, so this
+/// captures the combinations `ring` can actually sign with (PKCS#1 v1.5 or PSS,
+/// at SHA-256/384/512 — `ring` has no RSA signing support for SHA-1).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RsaSigningScheme {
+    /// RSASSA-PKCS1-v1_5 with SHA-256. This is the default, for backwards compatibility.
+    Pkcs1Sha256,
+
+    /// RSASSA-PKCS1-v1_5 with SHA-384.
+    Pkcs1Sha384,
+
+    /// RSASSA-PKCS1-v1_5 with SHA-512.
+    Pkcs1Sha512,
+
+    /// RSASSA-PSS with SHA-256.
+    PssSha256,
+
+    /// RSASSA-PSS with SHA-384.
+    PssSha384,
+
+    /// RSASSA-PSS with SHA-512.
+    PssSha512,
+}
+
+impl Default for RsaSigningScheme {
+    fn default() -> Self {
+        Self::Pkcs1Sha256
+    }
+}
+
+impl RsaSigningScheme {
+    /// The `ring` RSA encoding this scheme signs with.
+    fn ring_encoding(&self) -> &'static dyn ringsig::RsaEncoding {
+        match self {
+            Self::Pkcs1Sha256 => &ringsig::RSA_PKCS1_SHA256,
+            Self::Pkcs1Sha384 => &ringsig::RSA_PKCS1_SHA384,
+            Self::Pkcs1Sha512 => &ringsig::RSA_PKCS1_SHA512,
+            Self::PssSha256 => &ringsig::RSA_PSS_SHA256,
+            Self::PssSha384 => &ringsig::RSA_PSS_SHA384,
+            Self::PssSha512 => &ringsig::RSA_PSS_SHA512,
+        }
+    }
+}
+
+impl From<RsaSigningScheme> for SignatureAlgorithm {
+    fn from(scheme: RsaSigningScheme) -> Self {
+        match scheme {
+            RsaSigningScheme::Pkcs1Sha256 => Self::RsaSha256,
+            RsaSigningScheme::Pkcs1Sha384 => Self::RsaSha384,
+            RsaSigningScheme::Pkcs1Sha512 => Self::RsaSha512,
+            RsaSigningScheme::PssSha256 => Self::RsaPssSha256,
+            RsaSigningScheme::PssSha384 => Self::RsaPssSha384,
+            RsaSigningScheme::PssSha512 => Self::RsaPssSha512,
+        }
+    }
+}
+
 /// An RSA key pair.
 #[derive(Debug)]
 pub struct RsaKeyPair {
     pkcs8_der: SecretDocument,
     ring_pair: ringsig::RsaKeyPair,
     private_key: Zeroizing<Vec<u8>>,
+    scheme: RsaSigningScheme,
 }
 
 /// Represents a key pair that exists in memory and can be used to create cryptographic signatures.
@@ -144,7 +281,7 @@ impl Signer<Signature> for InMemorySigningKeyPair {
 
                 kp.ring_pair
                     .sign(
-                        &ringsig::RSA_PKCS1_SHA256,
+                        kp.scheme.ring_encoding(),
                         &ring::rand::SystemRandom::new(),
                         msg,
                         &mut signature,
@@ -200,7 +337,7 @@ impl Sign for InMemorySigningKeyPair {
 
     fn signature_algorithm(&self) -> Result<SignatureAlgorithm, Error> {
         Ok(match self {
-            Self::Rsa(_) => SignatureAlgorithm::RsaSha256,
+            Self::Rsa(kp) => kp.scheme.into(),
             Self::Ecdsa(kp) => {
                 // ring refuses to mix and match the bitness of curves and signature
                 // algorithms. e.g. it can't pair secp256r1 with SHA-384. It chooses
@@ -208,6 +345,11 @@ impl Sign for InMemorySigningKeyPair {
                 match kp.curve {
                     EcdsaCurve::Secp256r1 => SignatureAlgorithm::EcdsaSha256,
                     EcdsaCurve::Secp384r1 => SignatureAlgorithm::EcdsaSha384,
+                    // An EcdsaKeyPair is only ever constructed from a ring-backed
+                    // key, and ring has no secp256k1 support, so this is unreachable.
+                    EcdsaCurve::Secp256k1 => unreachable!(
+                        "EcdsaKeyPair is never constructed with secp256k1; ring has no signing support for it"
+                    ),
                 }
             }
             Self::Ed25519(_) => SignatureAlgorithm::Ed25519,
@@ -266,8 +408,13 @@ impl InMemorySigningKeyPair {
                     pkcs8_der,
                     ring_pair: pair,
                     private_key: Zeroizing::new(key.private_key.into_bytes().to_vec()),
+                    scheme: RsaSigningScheme::default(),
                 })))
             }
+            // ring has no secp256k1 signing support, so an in-memory signing key
+            // pair can't be constructed for it. secp256k1 certificates can still
+            // be verified; see `VerificationAlgorithm`.
+            KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256k1) => Err(Error::KeyPairGenerationError),
             KeyAlgorithm::Ecdsa(curve) => {
                 let pair = ringsig::EcdsaKeyPair::from_pkcs8(
                     curve.into(),
@@ -299,40 +446,214 @@ impl InMemorySigningKeyPair {
         Self::from_pkcs8_der(der.contents())
     }
 
+    /// Attempt to instantiate an ECDSA instance from SEC1 (RFC 5915) `ECPrivateKey` DER data.
+    ///
+    /// Most EC key material found in the wild (e.g. `openssl ecparam -genkey`, or a
+    /// `BEGIN EC PRIVATE KEY` PEM) is SEC1, not PKCS#8. `ECPrivateKey` is:
+    ///
+    /// ```text
+    /// ECPrivateKey ::= SEQUENCE {
+    ///     version        INTEGER { ecPrivkeyVer1(1) },
+    ///     privateKey     OCTET STRING,
+    ///     parameters [0] EXPLICIT ECParameters OPTIONAL,
+    ///     publicKey  [1] EXPLICIT BIT STRING OPTIONAL
+    /// }
+    /// ```
+    ///
+    /// This reads the named curve OID out of `parameters` to pick an [EcdsaCurve], then
+    /// wraps the *entire, unmodified* SEC1 DER as the `privateKey` OCTET STRING of a
+    /// PKCS#8 `OneAsymmetricKey` whose `privateKeyAlgorithm` is `id-ecPublicKey` with
+    /// that curve OID as parameters, and hands the result to [Self::from_pkcs8_der].
+    /// Since the original SEC1 bytes (including its own optional `publicKey` field) are
+    /// passed through untouched, `ring` still validates the public/private key
+    /// consistency if a public key was present.
+    ///
+    /// `parameters` using anything other than a named curve (e.g. `specifiedCurve`) is
+    /// not supported, matching [EcdsaCurve]'s OID-only curve resolution elsewhere in
+    /// this crate.
+    pub fn from_sec1_der(data: &[u8]) -> Result<Self, Error> {
+        let (outer_tag, outer_content, _) = read_der_tlv(data, 0)?;
+        if outer_tag != 0x30 {
+            return Err(Error::KeyPairGenerationError);
+        }
+
+        // version INTEGER
+        let (tag, _, pos) = read_der_tlv(data, outer_content.start)?;
+        if tag != 0x02 {
+            return Err(Error::KeyPairGenerationError);
+        }
+
+        // privateKey OCTET STRING
+        let (tag, _, pos) = read_der_tlv(data, pos)?;
+        if tag != 0x04 {
+            return Err(Error::KeyPairGenerationError);
+        }
+
+        // parameters [0] EXPLICIT ECParameters
+        if pos >= outer_content.end {
+            return Err(Error::UnknownEllipticCurve(
+                "SEC1 key has no named-curve parameters".into(),
+            ));
+        }
+        let (tag, params_content, _) = read_der_tlv(data, pos)?;
+        if tag != 0xa0 {
+            return Err(Error::UnknownEllipticCurve(
+                "SEC1 key has no named-curve parameters".into(),
+            ));
+        }
+
+        let (oid_tag, oid_content, _) = read_der_tlv(data, params_content.start)?;
+        if oid_tag != 0x06 {
+            return Err(Error::UnknownEllipticCurve(
+                "SEC1 key's ECParameters is not a named curve OID".into(),
+            ));
+        }
+
+        let curve_oid = bcder::Oid(Bytes::copy_from_slice(&data[oid_content]));
+        let curve = EcdsaCurve::try_from(&curve_oid)?;
+
+        let algorithm_identifier: crate::rfc5280::AlgorithmIdentifier =
+            KeyAlgorithm::Ecdsa(curve).into();
+        let mut algorithm_der = vec![];
+        algorithm_identifier
+            .encode_ref()
+            .write_encoded(bcder::Mode::Der, &mut algorithm_der)?;
+
+        let mut private_key_field = vec![0x04];
+        private_key_field.extend(der_length(data.len()));
+        private_key_field.extend_from_slice(data);
+
+        let mut body = vec![0x02, 0x01, 0x00]; // version 0
+        body.extend_from_slice(&algorithm_der);
+        body.extend_from_slice(&private_key_field);
+
+        let mut pkcs8_der = vec![0x30];
+        pkcs8_der.extend(der_length(body.len()));
+        pkcs8_der.extend_from_slice(&body);
+
+        Self::from_pkcs8_der(pkcs8_der)
+    }
+
+    /// PEM variant of [Self::from_sec1_der].
+    ///
+    /// The PEM should have an `EC PRIVATE KEY` tag, as is conventional for SEC1.
+    pub fn from_sec1_pem(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let der = pem::parse(data.as_ref()).map_err(Error::PemDecode)?;
+
+        Self::from_sec1_der(der.contents())
+    }
+
+    /// Attempt to instantiate an instance from DER data of unknown encoding.
+    ///
+    /// Tries [Self::from_pkcs8_der] first, then falls back to [Self::from_sec1_der].
+    /// Prefer the specific constructor when the encoding is known.
+    pub fn from_der_any(data: &[u8]) -> Result<Self, Error> {
+        Self::from_pkcs8_der(data).or_else(|_| Self::from_sec1_der(data))
+    }
+
     /// Generate a random key pair given a key algorithm and optional ECDSA signing algorithm.
     ///
     /// The raw PKCS#8 document is returned to facilitate access to the private key.
     ///
     /// Not attempt is made to protect the private key in memory.
+    ///
+    /// RSA key generation requires the `rustcrypto` crate feature, since `ring` has
+    /// no RSA key generation support; see [Self::generate_random_rsa] if you need a
+    /// modulus size other than the 2048-bit default this uses.
     pub fn generate_random(key_algorithm: KeyAlgorithm) -> Result<Self, Error> {
+        match key_algorithm {
+            #[cfg(feature = "rustcrypto")]
+            KeyAlgorithm::Rsa => return Self::generate_random_rsa(2048),
+            #[cfg(not(feature = "rustcrypto"))]
+            KeyAlgorithm::Rsa => return Err(Error::RsaKeyGenerationNotSupported),
+            _ => {}
+        }
+
         let rng = SystemRandom::new();
 
         let document = match key_algorithm {
             KeyAlgorithm::Ed25519 => ringsig::Ed25519KeyPair::generate_pkcs8(&rng)
                 .map_err(|_| Error::KeyPairGenerationError),
+            // ring has no secp256k1 signing support; this curve is verification-only.
+            KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256k1) => Err(Error::KeyPairGenerationError),
             KeyAlgorithm::Ecdsa(curve) => ringsig::EcdsaKeyPair::generate_pkcs8(curve.into(), &rng)
                 .map_err(|_| Error::KeyPairGenerationError),
-            KeyAlgorithm::Rsa => Err(Error::RsaKeyGenerationNotSupported),
+            KeyAlgorithm::Rsa => unreachable!("KeyAlgorithm::Rsa is handled above"),
         }?;
 
         Self::from_pkcs8_der(document.as_ref())
     }
 
+    /// Generate a random RSA key pair with a caller-supplied modulus size in bits.
+    ///
+    /// `ring` cannot generate RSA keys, so this uses the pure-Rust `rsa` crate to
+    /// generate a [rsa::RsaPrivateKey] of the requested size, exports it to PKCS#8
+    /// DER, and feeds that document back through [Self::from_pkcs8_der] so the
+    /// result is a normal [Self::Rsa] backed by `ring` for signing.
+    #[cfg(feature = "rustcrypto")]
+    pub fn generate_random_rsa(bits: usize) -> Result<Self, Error> {
+        use rsa::pkcs8::EncodePrivateKey;
+
+        let mut rng = rand::thread_rng();
+
+        let private_key =
+            rsa::RsaPrivateKey::new(&mut rng, bits).map_err(|_| Error::KeyPairGenerationError)?;
+
+        let document = private_key
+            .to_pkcs8_der()
+            .map_err(|_| Error::KeyPairGenerationError)?;
+
+        Self::from_pkcs8_der(document.as_bytes())
+    }
+
     /// Attempt to resolve a verification algorithm for this key pair.
     ///
     /// This is a wrapper around [SignatureAlgorithm::resolve_verification_algorithm()]
     /// with our bound [KeyAlgorithm]. However, since there are no parameters
     /// that can result in wrong choices, this is guaranteed to always work
     /// and doesn't require `Result`.
-    pub fn verification_algorithm(
-        &self,
-    ) -> Result<&'static dyn ringsig::VerificationAlgorithm, Error> {
+    pub fn verification_algorithm(&self) -> Result<VerificationAlgorithm, Error> {
         Ok(self.signature_algorithm()?
             .resolve_verification_algorithm(self.key_algorithm().expect("key algorithm should be known for InMemorySigningKeyPair")).expect(
             "illegal combination of key algorithm in signature algorithm: this should not occur"
         ))
     }
 
+    /// Select the RSA padding scheme and digest this instance signs with.
+    ///
+    /// Only meaningful for [Self::Rsa]: other variants are returned unchanged, since
+    /// ECDSA and Ed25519 each only ever sign one way. Defaults to
+    /// [RsaSigningScheme::Pkcs1Sha256] for keys constructed via [Self::from_pkcs8_der]
+    /// or [Self::generate_random]; use this to opt into RSA-PSS or a different digest,
+    /// e.g. for TUF (RSA-PSS with SHA-256/512) or WebCrypto/WASI interop.
+    pub fn with_rsa_signing_scheme(mut self, scheme: RsaSigningScheme) -> Self {
+        if let Self::Rsa(kp) = &mut self {
+            kp.scheme = scheme;
+        }
+
+        self
+    }
+
+    /// Verify that this key pair's private key corresponds to `certificate`'s public key.
+    ///
+    /// Signs a fixed challenge message with this key pair, then verifies that
+    /// signature against `certificate`'s `subject_public_key_info` using
+    /// [Self::verification_algorithm]. This is the standard pre-flight check when
+    /// assembling a server key+cert pair from independently-sourced files: a
+    /// mismatched pair fails clearly here instead of at TLS handshake time.
+    pub fn verify_matches_certificate(&self, certificate: &crate::X509Certificate) -> Result<(), Error> {
+        const CHALLENGE: &[u8] = b"x509-certificate key/certificate consistency check";
+
+        let signature = Signer::try_sign(self, CHALLENGE)
+            .map_err(|_| Error::CertificateSignatureVerificationFailed)?;
+
+        self.verification_algorithm()?.verify(
+            &certificate.public_key_data(),
+            CHALLENGE,
+            signature.as_ref(),
+        )
+    }
+
     /// Serialize this instance to a PKCS#8 [OneAsymmetricKey] ASN.1 structure.
     pub fn to_pkcs8_one_asymmetric_key_der(&self) -> Zeroizing<Vec<u8>> {
         match self {
@@ -343,6 +664,49 @@ impl InMemorySigningKeyPair {
     }
 }
 
+/// Read a DER tag-length-value header, returning `(tag, content_byte_range, offset_after)`.
+///
+/// Only definite-form lengths are supported, which is all DER ever produces.
+fn read_der_tlv(data: &[u8], pos: usize) -> Result<(u8, std::ops::Range<usize>, usize), Error> {
+    let tag = *data.get(pos).ok_or(Error::KeyPairGenerationError)?;
+    let len_byte = *data.get(pos + 1).ok_or(Error::KeyPairGenerationError)?;
+
+    let (len, header_len) = if len_byte & 0x80 == 0 {
+        (len_byte as usize, 2)
+    } else {
+        let num_len_bytes = (len_byte & 0x7f) as usize;
+        let len_bytes = data
+            .get(pos + 2..pos + 2 + num_len_bytes)
+            .ok_or(Error::KeyPairGenerationError)?;
+        let len = len_bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+
+        (len, 2 + num_len_bytes)
+    };
+
+    let content_start = pos + header_len;
+    let content_end = content_start + len;
+    if content_end > data.len() {
+        return Err(Error::KeyPairGenerationError);
+    }
+
+    Ok((tag, content_start..content_end, content_end))
+}
+
+/// Encode a DER definite-form length, per X.690 section 8.1.3.
+fn der_length(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        vec![len as u8]
+    } else {
+        let bytes = len.to_be_bytes();
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+        let len_bytes = &bytes[first_nonzero..];
+
+        let mut out = vec![0x80 | len_bytes.len() as u8];
+        out.extend_from_slice(len_bytes);
+        out
+    }
+}
+
 impl From<&InMemorySigningKeyPair> for KeyAlgorithm {
     fn from(key: &InMemorySigningKeyPair) -> Self {
         match key {
@@ -355,12 +719,19 @@ impl From<&InMemorySigningKeyPair> for KeyAlgorithm {
 
 #[cfg(test)]
 mod test {
-    use {super::*, crate::rfc5280, crate::testutil::*, ringsig::UnparsedPublicKey};
+    use {super::*, crate::rfc5280, crate::testutil::*};
 
     #[test]
     fn generate_random_ecdsa() {
         for curve in EcdsaCurve::all() {
-            InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ecdsa(*curve)).unwrap();
+            let result = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ecdsa(*curve));
+
+            // ring has no secp256k1 signing support; this curve is verification-only.
+            if *curve == EcdsaCurve::Secp256k1 {
+                assert!(result.is_err());
+            } else {
+                result.unwrap();
+            }
         }
     }
 
@@ -370,10 +741,32 @@ mod test {
     }
 
     #[test]
+    #[cfg(not(feature = "rustcrypto"))]
     fn generate_random_rsa() {
         assert!(InMemorySigningKeyPair::generate_random(KeyAlgorithm::Rsa).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "rustcrypto")]
+    fn generate_random_rsa() {
+        let key = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Rsa).unwrap();
+        assert!(matches!(key, InMemorySigningKeyPair::Rsa(_)));
+        assert_eq!(key.key_algorithm(), Some(KeyAlgorithm::Rsa));
+    }
+
+    #[test]
+    #[cfg(feature = "rustcrypto")]
+    fn generate_random_rsa_custom_modulus() {
+        let key = InMemorySigningKeyPair::generate_random_rsa(3072).unwrap();
+
+        let message = b"hello, world";
+        let signature = Signer::try_sign(&key, message).unwrap();
+        key.verification_algorithm()
+            .unwrap()
+            .verify(&key.public_key_data(), message, signature.as_ref())
+            .unwrap();
+    }
+
     #[test]
     fn signing_key_from_ecdsa_pkcs8() {
         let rng = ring::rand::SystemRandom::new();
@@ -422,6 +815,86 @@ mod test {
         }
     }
 
+    #[test]
+    fn signing_key_from_sec1_der() {
+        let rng = ring::rand::SystemRandom::new();
+
+        for (alg, expected_curve) in [
+            (
+                &ringsig::ECDSA_P256_SHA256_ASN1_SIGNING,
+                EcdsaCurve::Secp256r1,
+            ),
+            (
+                &ringsig::ECDSA_P384_SHA384_ASN1_SIGNING,
+                EcdsaCurve::Secp384r1,
+            ),
+        ] {
+            let pkcs8_doc = ringsig::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+
+            // Pull the embedded SEC1 ECPrivateKey out of the PKCS#8 document ring
+            // just generated for us, so we exercise from_sec1_der() with real,
+            // ring-validated key material rather than a hand-rolled fixture.
+            let key_pair_asn1 = Constructed::decode(pkcs8_doc.as_ref(), bcder::Mode::Der, |cons| {
+                OneAsymmetricKey::take_from(cons)
+            })
+            .unwrap();
+            let sec1_der = key_pair_asn1.private_key.into_bytes().to_vec();
+
+            let signing_key = InMemorySigningKeyPair::from_sec1_der(&sec1_der).unwrap();
+            assert_eq!(
+                signing_key.key_algorithm(),
+                Some(KeyAlgorithm::Ecdsa(expected_curve))
+            );
+
+            let message = b"hello, world";
+            let signature = Signer::try_sign(&signing_key, message).unwrap();
+            signing_key
+                .verification_algorithm()
+                .unwrap()
+                .verify(&signing_key.public_key_data(), message, signature.as_ref())
+                .unwrap();
+
+            let pem_data = pem::Pem::new("EC PRIVATE KEY", sec1_der.clone()).to_string();
+            let signing_key = InMemorySigningKeyPair::from_sec1_pem(pem_data.as_bytes()).unwrap();
+            assert_eq!(
+                signing_key.key_algorithm(),
+                Some(KeyAlgorithm::Ecdsa(expected_curve))
+            );
+
+            let signing_key = InMemorySigningKeyPair::from_der_any(&sec1_der).unwrap();
+            assert_eq!(
+                signing_key.key_algorithm(),
+                Some(KeyAlgorithm::Ecdsa(expected_curve))
+            );
+        }
+
+        // from_der_any() should resolve a PKCS#8 document via that path directly,
+        // without needing to fall back to the SEC1 parse.
+        let pkcs8_doc = ringsig::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let signing_key = InMemorySigningKeyPair::from_der_any(pkcs8_doc.as_ref()).unwrap();
+        assert!(matches!(signing_key, InMemorySigningKeyPair::Ed25519(_)));
+    }
+
+    #[test]
+    fn signing_key_from_sec1_der_rejects_malformed_input() {
+        // Too short to even contain a length byte.
+        assert!(matches!(
+            InMemorySigningKeyPair::from_sec1_der(&[0x30]),
+            Err(Error::KeyPairGenerationError)
+        ));
+
+        // A well-formed SEQUENCE { INTEGER, OCTET STRING } with no [0] parameters.
+        let no_params = [
+            0x30, 0x06, // SEQUENCE, length 6
+            0x02, 0x01, 0x01, // INTEGER 1
+            0x04, 0x01, 0x00, // OCTET STRING, 1 byte
+        ];
+        assert!(matches!(
+            InMemorySigningKeyPair::from_sec1_der(&no_params),
+            Err(Error::UnknownEllipticCurve(_))
+        ));
+    }
+
     #[test]
     fn signing_key_from_ed25519_pkcs8() {
         let rng = ring::rand::SystemRandom::new();
@@ -450,6 +923,12 @@ mod test {
     #[test]
     fn ecdsa_self_signed_certificate_verification() {
         for curve in EcdsaCurve::all() {
+            // ring has no secp256k1 signing support, so a self-signed certificate
+            // can't be produced for it; it is verification-only.
+            if *curve == EcdsaCurve::Secp256k1 {
+                continue;
+            }
+
             let (cert, _) = self_signed_ecdsa_key_pair(Some(*curve));
             cert.verify_signed_by_certificate(&cert).unwrap();
 
@@ -460,6 +939,7 @@ mod test {
             let expected = match curve {
                 EcdsaCurve::Secp256r1 => SignatureAlgorithm::EcdsaSha256,
                 EcdsaCurve::Secp384r1 => SignatureAlgorithm::EcdsaSha384,
+                EcdsaCurve::Secp256k1 => unreachable!("secp256k1 is skipped above"),
             };
             assert_eq!(tbs_signature_algorithm, expected);
 
@@ -474,6 +954,7 @@ mod test {
             let expected = match curve {
                 EcdsaCurve::Secp256r1 => crate::algorithm::OID_EC_SECP256R1,
                 EcdsaCurve::Secp384r1 => crate::algorithm::OID_EC_SECP384R1,
+                EcdsaCurve::Secp256k1 => unreachable!("secp256k1 is skipped above"),
             };
             assert!(spki.algorithm.parameters.is_some());
             assert_eq!(
@@ -506,11 +987,96 @@ mod test {
 
         let signature = Signer::try_sign(&key, message).unwrap();
 
-        let public_key = UnparsedPublicKey::new(
-            key.verification_algorithm().unwrap(),
-            cert.public_key_data(),
-        );
+        key.verification_algorithm()
+            .unwrap()
+            .verify(&cert.public_key_data(), message, signature.as_ref())
+            .unwrap();
+    }
+
+    #[test]
+    fn rsa_signing_scheme_roundtrip() {
+        for (scheme, expected_algorithm) in [
+            (RsaSigningScheme::Pkcs1Sha256, SignatureAlgorithm::RsaSha256),
+            (RsaSigningScheme::Pkcs1Sha384, SignatureAlgorithm::RsaSha384),
+            (RsaSigningScheme::Pkcs1Sha512, SignatureAlgorithm::RsaSha512),
+            (RsaSigningScheme::PssSha256, SignatureAlgorithm::RsaPssSha256),
+            (RsaSigningScheme::PssSha384, SignatureAlgorithm::RsaPssSha384),
+            (RsaSigningScheme::PssSha512, SignatureAlgorithm::RsaPssSha512),
+        ] {
+            let key = rsa_private_key().with_rsa_signing_scheme(scheme);
+            assert_eq!(key.signature_algorithm().unwrap(), expected_algorithm);
+
+            let message = b"hello, world";
+            let signature = Signer::try_sign(&key, message).unwrap();
+
+            key.verification_algorithm()
+                .unwrap()
+                .verify(&key.public_key_data(), message, signature.as_ref())
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn with_rsa_signing_scheme_is_noop_for_non_rsa_keys() {
+        let key = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519).unwrap();
+        let key = key.with_rsa_signing_scheme(RsaSigningScheme::PssSha512);
+        assert_eq!(key.signature_algorithm().unwrap(), SignatureAlgorithm::Ed25519);
+    }
+
+    #[test]
+    fn verify_matches_certificate() {
+        let key = rsa_private_key();
+        let cert = rsa_cert();
+
+        key.verify_matches_certificate(&cert).unwrap();
 
-        public_key.verify(message, signature.as_ref()).unwrap();
+        let (other_cert, _) = self_signed_ed25519_key_pair();
+        assert!(key.verify_matches_certificate(&other_cert).is_err());
+    }
+
+    #[test]
+    fn remote_key_pair_wrapper() {
+        // A fake "remote" that just forwards to an in-memory key pair, standing in
+        // for a real HSM/KMS/TPM for the purposes of this test.
+        struct MockRemote(InMemorySigningKeyPair);
+
+        impl RemoteKeyPair for MockRemote {
+            fn key_algorithm(&self) -> Option<KeyAlgorithm> {
+                self.0.key_algorithm()
+            }
+
+            fn public_key_data(&self) -> Bytes {
+                self.0.public_key_data()
+            }
+
+            fn signature_algorithm(&self) -> Result<SignatureAlgorithm, Error> {
+                self.0.signature_algorithm()
+            }
+
+            fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+                Ok(Signer::try_sign(&self.0, msg)
+                    .map_err(|_| Error::CertificateSignatureVerificationFailed)?
+                    .into())
+            }
+        }
+
+        let inner = InMemorySigningKeyPair::generate_random(KeyAlgorithm::Ed25519).unwrap();
+        let remote = RemoteSigningKeyPair::new(MockRemote(inner));
+
+        assert_eq!(remote.key_algorithm(), Some(KeyAlgorithm::Ed25519));
+        assert!(remote.private_key_data().is_none());
+        assert!(remote.rsa_primes().unwrap().is_none());
+
+        let message = b"hello, world";
+        let signature = Signer::try_sign(&remote, message).unwrap();
+
+        let verifier = remote
+            .signature_algorithm()
+            .unwrap()
+            .resolve_verification_algorithm(remote.key_algorithm().unwrap())
+            .unwrap();
+        verifier
+            .verify(&remote.public_key_data(), message, signature.as_ref())
+            .unwrap();
     }
 }
@@ -0,0 +1,263 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Parsing and serialization of OpenSSH wire-format public keys.
+//!
+//! This implements just enough of the binary format described in
+//! [RFC 4253 section 6.6](https://www.rfc-editor.org/rfc/rfc4253#section-6.6) to
+//! move keys between SSH tooling (`authorized_keys` lines, `ssh-keygen` output) and
+//! this crate's [KeyAlgorithm]/[EcdsaCurve] enums, without pulling in a dedicated
+//! SSH parsing crate.
+
+use crate::{EcdsaCurve, KeyAlgorithm, X509CertificateError as Error};
+
+/// The raw key material carried by a [PublicKey], in OpenSSH wire-format byte layout.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PublicKeyMaterial {
+    /// An RSA key, as the `e` (public exponent) and `n` (modulus) mpints.
+    ///
+    /// Each field is the exact wire-format mpint encoding (RFC 4251 section 5): a
+    /// two's-complement, big-endian integer, with a leading `0x00` byte present if
+    /// the high bit of the first byte would otherwise be set.
+    Rsa { e: Vec<u8>, n: Vec<u8> },
+
+    /// An ECDSA key, as the uncompressed SEC1 point (`0x04 || X || Y`).
+    Ecdsa { point: Vec<u8> },
+
+    /// An Ed25519 key, as the 32 raw public key bytes.
+    Ed25519 { key: [u8; 32] },
+}
+
+/// An OpenSSH wire-format public key.
+///
+/// This models the body of an `authorized_keys` line or `ssh-keygen -e` export:
+/// a key type string followed by type-specific fields, all length-prefixed per
+/// RFC 4251 section 5.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicKey {
+    pub algorithm: KeyAlgorithm,
+    pub material: PublicKeyMaterial,
+}
+
+impl PublicKey {
+    /// Parse an OpenSSH public key line, such as `ssh-ed25519 AAAA... comment`.
+    ///
+    /// Only the key type and base64-encoded blob fields are consulted; a trailing
+    /// comment, if present, is ignored.
+    pub fn from_openssh(s: &str) -> Result<Self, Error> {
+        let mut fields = s.trim().split_ascii_whitespace();
+
+        let key_type = fields
+            .next()
+            .ok_or_else(|| Error::UnknownKeyAlgorithm("empty OpenSSH public key line".into()))?;
+        let blob_b64 = fields.next().ok_or_else(|| {
+            Error::UnknownKeyAlgorithm("OpenSSH public key line is missing its key blob".into())
+        })?;
+
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let blob = STANDARD
+            .decode(blob_b64.as_bytes())
+            .map_err(|e| Error::UnknownKeyAlgorithm(format!("invalid base64 in OpenSSH key blob: {}", e)))?;
+
+        let mut pos = 0;
+        let blob_type = read_string(&blob, &mut pos)?;
+        if blob_type != key_type.as_bytes() {
+            return Err(Error::UnknownKeyAlgorithm(format!(
+                "OpenSSH key type {} does not match blob's embedded type {}",
+                key_type,
+                String::from_utf8_lossy(blob_type),
+            )));
+        }
+
+        let (algorithm, material) = match key_type {
+            "ssh-rsa" => {
+                let e = read_string(&blob, &mut pos)?.to_vec();
+                let n = read_string(&blob, &mut pos)?.to_vec();
+                (KeyAlgorithm::Rsa, PublicKeyMaterial::Rsa { e, n })
+            }
+            "ecdsa-sha2-nistp256" | "ecdsa-sha2-nistp384" => {
+                let curve_name = read_string(&blob, &mut pos)?;
+                let point = read_string(&blob, &mut pos)?.to_vec();
+
+                let curve = match curve_name {
+                    b"nistp256" => EcdsaCurve::Secp256r1,
+                    b"nistp384" => EcdsaCurve::Secp384r1,
+                    other => {
+                        return Err(Error::UnknownEllipticCurve(String::from_utf8_lossy(other).into_owned()))
+                    }
+                };
+
+                if key_type.as_bytes() != [&b"ecdsa-sha2-"[..], curve_name].concat().as_slice() {
+                    return Err(Error::UnknownKeyAlgorithm(format!(
+                        "OpenSSH key type {} does not match curve {}",
+                        key_type,
+                        String::from_utf8_lossy(curve_name),
+                    )));
+                }
+
+                if point.first() != Some(&0x04) {
+                    return Err(Error::UnknownKeyAlgorithm(
+                        "ECDSA point is not in uncompressed (0x04-prefixed) form".into(),
+                    ));
+                }
+
+                (KeyAlgorithm::Ecdsa(curve), PublicKeyMaterial::Ecdsa { point })
+            }
+            "ssh-ed25519" => {
+                let key = read_string(&blob, &mut pos)?;
+                let key: [u8; 32] = key
+                    .try_into()
+                    .map_err(|_| Error::UnknownKeyAlgorithm("ssh-ed25519 key is not 32 bytes".into()))?;
+
+                (KeyAlgorithm::Ed25519, PublicKeyMaterial::Ed25519 { key })
+            }
+            other => return Err(Error::UnknownKeyAlgorithm(format!("unsupported OpenSSH key type: {}", other))),
+        };
+
+        if pos != blob.len() {
+            return Err(Error::UnknownKeyAlgorithm(
+                "OpenSSH key blob has trailing data after its fields".into(),
+            ));
+        }
+
+        Ok(Self { algorithm, material })
+    }
+
+    /// Serialize this key back to its OpenSSH wire-format blob.
+    ///
+    /// This reproduces the exact bytes [Self::from_openssh] read: each field is
+    /// written back verbatim from [PublicKeyMaterial], with no re-normalization.
+    fn to_blob(&self) -> Vec<u8> {
+        let mut out = vec![];
+
+        write_string(&mut out, self.key_type().as_bytes());
+
+        match &self.material {
+            PublicKeyMaterial::Rsa { e, n } => {
+                write_string(&mut out, e);
+                write_string(&mut out, n);
+            }
+            PublicKeyMaterial::Ecdsa { point } => {
+                let curve_name: &[u8] = match self.algorithm {
+                    KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1) => b"nistp256",
+                    KeyAlgorithm::Ecdsa(EcdsaCurve::Secp384r1) => b"nistp384",
+                    KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256k1) => b"secp256k1",
+                    _ => unreachable!("PublicKeyMaterial::Ecdsa is only ever paired with KeyAlgorithm::Ecdsa"),
+                };
+                write_string(&mut out, curve_name);
+                write_string(&mut out, point);
+            }
+            PublicKeyMaterial::Ed25519 { key } => {
+                write_string(&mut out, key);
+            }
+        }
+
+        out
+    }
+
+    /// The OpenSSH key type string, e.g. `ssh-ed25519` or `ecdsa-sha2-nistp256`.
+    pub fn key_type(&self) -> &'static str {
+        match (&self.algorithm, &self.material) {
+            (KeyAlgorithm::Rsa, _) => "ssh-rsa",
+            (KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1), _) => "ecdsa-sha2-nistp256",
+            (KeyAlgorithm::Ecdsa(EcdsaCurve::Secp384r1), _) => "ecdsa-sha2-nistp384",
+            (KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256k1), _) => "ecdsa-sha2-secp256k1",
+            (KeyAlgorithm::Ed25519, _) => "ssh-ed25519",
+        }
+    }
+
+    /// Serialize this key to an OpenSSH public key line, without a trailing comment.
+    pub fn to_openssh(&self) -> String {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        format!("{} {}", self.key_type(), STANDARD.encode(self.to_blob()))
+    }
+}
+
+/// Read a length-prefixed `string` (or `mpint`) field per RFC 4251 section 5.
+fn read_string<'a>(data: &'a [u8], pos: &mut usize) -> Result<&'a [u8], Error> {
+    let len_bytes = data.get(*pos..*pos + 4).ok_or_else(|| {
+        Error::UnknownKeyAlgorithm("truncated OpenSSH key blob: missing a length prefix".into())
+    })?;
+    let len = u32::from_be_bytes(len_bytes.try_into().expect("slice is 4 bytes")) as usize;
+
+    let start = *pos + 4;
+    let value = data
+        .get(start..start + len)
+        .ok_or_else(|| Error::UnknownKeyAlgorithm("truncated OpenSSH key blob: field runs past the end".into()))?;
+
+    *pos = start + len;
+
+    Ok(value)
+}
+
+/// Write a length-prefixed `string` (or `mpint`) field per RFC 4251 section 5.
+fn write_string(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(data);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ssh_ed25519_roundtrip() -> Result<(), Error> {
+        let type_and_blob = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIJqpo1J3ViMGqoJObCObbHV7xjJLIsgN7Pw4aMh2y9oP";
+        let line = format!("{} comment", type_and_blob);
+
+        let key = PublicKey::from_openssh(&line)?;
+        assert_eq!(key.algorithm, KeyAlgorithm::Ed25519);
+        assert!(matches!(key.material, PublicKeyMaterial::Ed25519 { .. }));
+
+        // Re-serializing drops the comment but reproduces the type + blob exactly.
+        assert_eq!(key.to_openssh(), type_and_blob);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ssh_ecdsa_p256_roundtrip() -> Result<(), Error> {
+        // A synthetic, but structurally valid, ecdsa-sha2-nistp256 key: an
+        // uncompressed point of 0x04 followed by 32-byte X and Y coordinates.
+        let mut point = vec![0x04u8];
+        point.extend([0x11; 32]);
+        point.extend([0x22; 32]);
+
+        let mut blob = vec![];
+        write_string(&mut blob, b"ecdsa-sha2-nistp256");
+        write_string(&mut blob, b"nistp256");
+        write_string(&mut blob, &point);
+
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        let line = format!("ecdsa-sha2-nistp256 {}", STANDARD.encode(&blob));
+
+        let key = PublicKey::from_openssh(&line)?;
+        assert_eq!(key.algorithm, KeyAlgorithm::Ecdsa(EcdsaCurve::Secp256r1));
+        assert_eq!(key.material, PublicKeyMaterial::Ecdsa { point });
+        assert_eq!(key.to_openssh(), line);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_mismatched_type_and_unknown_type() {
+        assert!(PublicKey::from_openssh("ssh-rsa AAAAC3NzaC1lZDI1NTE5AAAAIJqpo1J3ViMGqoJObCObbHV7xjJLIsgN7Pw4aMh2y9oP").is_err());
+        assert!(PublicKey::from_openssh("ssh-dss AAAA").is_err());
+        assert!(PublicKey::from_openssh("").is_err());
+    }
+
+    #[test]
+    fn wire_format_helpers_roundtrip() {
+        let mut out = vec![];
+        write_string(&mut out, b"ssh-ed25519");
+        write_string(&mut out, &[0x01, 0x02, 0x03]);
+
+        let mut pos = 0;
+        assert_eq!(read_string(&out, &mut pos).unwrap(), b"ssh-ed25519");
+        assert_eq!(read_string(&out, &mut pos).unwrap(), &[0x01, 0x02, 0x03]);
+        assert_eq!(pos, out.len());
+    }
+}
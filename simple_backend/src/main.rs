@@ -2,21 +2,28 @@ use chrono::{DateTime, Utc};
 use ethers::prelude::*;
 use ethers::providers::{Provider, Http};
 use ethers::signers::{LocalWallet, Signer};
-use ethers::types::{Address, U256};
+use ethers::types::{Address, BlockId, BlockNumber, TransactionRequest, H256, U256, U64};
+use ethers::types::transaction::eip2718::TypedTransaction;
 use postgres_native_tls::MakeTlsConnector;
+use postgres::types::ToSql;
 use native_tls::TlsConnector;
 use r2d2::Pool;
 use r2d2_postgres::PostgresConnectionManager;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tiny_http::{Header, Method, Request, Response, Server};
 use tokio::sync::oneshot;
 use uuid::Uuid;
-use secp256k1::{SecretKey, Secp256k1};
-use rand::RngCore;
+use secp256k1::{SecretKey, PublicKey, Secp256k1, Scalar};
+use bip39::{Language, Mnemonic};
+use hmac::{Hmac, Mac};
+use rust_decimal::Decimal;
+use sha2::Sha512;
 
 // Data structures matching PostgreSQL schema from shared/schema.ts
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +48,9 @@ struct Wallet {
     last_activity: Option<String>,
     #[serde(rename = "createdAt")]
     created_at: String,
+    #[serde(rename = "derivationIndex")]
+    derivation_index: Option<i32>,
+    network: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,8 +65,13 @@ struct Activity {
     status: String,
     #[serde(rename = "transactionHash")]
     transaction_hash: Option<String>,
+    #[serde(rename = "gasUsed")]
+    gas_used: Option<String>,
     #[serde(rename = "createdAt")]
     created_at: String,
+    network: String,
+    #[serde(rename = "explorerUrl")]
+    explorer_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,8 +88,13 @@ struct SystemMetrics {
     cpu_usage: i32,
     #[serde(rename = "memoryUsage")]
     memory_usage: i32,
+    #[serde(rename = "bnbPriceUsd")]
+    bnb_price_usd: Option<String>,
+    #[serde(rename = "bnbChange24h")]
+    bnb_change_24h: Option<String>,
     #[serde(rename = "createdAt")]
     created_at: String,
+    network: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +106,54 @@ struct BulkWalletRequest {
     label_prefix: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TransactionSendRequest {
+    #[serde(rename = "fromWalletId")]
+    from_wallet_id: String,
+    #[serde(rename = "toAddress")]
+    to_address: String,
+    #[serde(rename = "amountWei")]
+    amount_wei: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScreenRequest {
+    addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AddressVerdict {
+    address: String,
+    #[serde(flatten)]
+    verdict: risk::Verdict,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BlocklistEntryRequest {
+    address: String,
+    reason: Option<String>,
+}
+
+/// A single call-frame from a `debug_traceTransaction` call tracer response,
+/// modeled after standard EVM vm-tracing output: the top-level frame is the
+/// transaction itself, and `calls` holds any nested CALL/DELEGATECALL/
+/// STATICCALL/CREATE frames it made, recursively.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CallFrame {
+    #[serde(rename = "type")]
+    frame_type: String,
+    from: String,
+    to: Option<String>,
+    value: Option<String>,
+    #[serde(rename = "gasUsed")]
+    gas_used: Option<String>,
+    input: Option<String>,
+    output: Option<String>,
+    error: Option<String>,
+    #[serde(default)]
+    calls: Vec<CallFrame>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Stats {
     #[serde(rename = "totalWallets")]
@@ -94,44 +162,139 @@ struct Stats {
     active_wallets: usize,
     #[serde(rename = "totalBalance")]
     total_balance: String,
+    #[serde(rename = "totalBalanceUsd")]
+    total_balance_usd: Option<String>,
+    network: String,
+}
+
+/// A page of activities plus opaque keyset cursors for the adjacent pages,
+/// in the spirit of a `Link: rel="next"/"prev"` header — `next` continues
+/// toward older activities, `prev` rewinds toward newer ones. Either is
+/// `None` once there's nothing further in that direction.
+#[derive(Debug, Serialize, Deserialize)]
+struct ActivityPage {
+    activities: Vec<Activity>,
+    next: Option<String>,
+    prev: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RateResponse {
+    #[serde(rename = "usdPerBnb")]
+    usd_per_bnb: String,
+    #[serde(rename = "ageSeconds")]
+    age_seconds: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WalletExport {
+    mnemonic: String,
+    #[serde(rename = "derivationPath")]
+    derivation_path: String,
+    #[serde(rename = "indexRangeStart")]
+    index_range_start: Option<i64>,
+    #[serde(rename = "indexRangeEnd")]
+    index_range_end: Option<i64>,
+    #[serde(rename = "walletCount")]
+    wallet_count: i64,
 }
 
 // Thread-safe database connection pool type
 type DbPool = Pool<PostgresConnectionManager<MakeTlsConnector>>;
 
+// Which BNB Smart Chain network this instance talks to. Selected once at
+// startup via the `BSC_NETWORK` env var (`mainnet` or `testnet`, default
+// `mainnet`) and threaded through every blockchain-touching call from there,
+// so wallet/activity records always carry the network they were created
+// under and testnet/mainnet data can never mix in the same database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Network {
+    BscMainnet,
+    BscTestnet,
+}
+
+impl Network {
+    fn from_env() -> Self {
+        match env::var("BSC_NETWORK").as_deref() {
+            Ok("testnet") => Network::BscTestnet,
+            _ => Network::BscMainnet,
+        }
+    }
+
+    fn chain_id(self) -> u64 {
+        match self {
+            Network::BscMainnet => 56,
+            Network::BscTestnet => 97,
+        }
+    }
+
+    fn default_rpc_url(self) -> &'static str {
+        match self {
+            Network::BscMainnet => "https://bsc-mainnet.core.chainstack.com",
+            Network::BscTestnet => "https://data-seed-prebsc-1-s1.binance.org:8545",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Network::BscMainnet => "Mainnet",
+            Network::BscTestnet => "Testnet",
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Network::BscMainnet => "mainnet",
+            Network::BscTestnet => "testnet",
+        }
+    }
+
+    // A BscScan explorer link for a transaction hash, pointed at the
+    // subdomain matching this network.
+    fn explorer_tx_url(self, tx_hash: &str) -> String {
+        let host = match self {
+            Network::BscMainnet => "bscscan.com",
+            Network::BscTestnet => "testnet.bscscan.com",
+        };
+        format!("https://{}/tx/{}", host, tx_hash)
+    }
+}
+
 // Blockchain service for BNB Smart Chain connectivity via Quicknode
 struct BlockchainService {
     provider: Provider<Http>,
     chain_id: u64,
+    network: Network,
 }
 
 impl BlockchainService {
-    async fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+    async fn new(network: Network) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let quicknode_url = env::var("QUICKNODE_BSC_URL")
-            .unwrap_or_else(|_| "https://bsc-mainnet.core.chainstack.com".to_string());
-        
+            .unwrap_or_else(|_| network.default_rpc_url().to_string());
+
         let quicknode_token = env::var("QUICKNODE_BSC_TOKEN").ok();
-        
+
         let provider_url = if let Some(token) = quicknode_token {
             format!("{}?token={}", quicknode_url, token)
         } else {
             quicknode_url
         };
-        
-        println!("🔗 Connecting to BNB Smart Chain via Quicknode...");
+
+        println!("🔗 Connecting to BNB Smart Chain ({}) via Quicknode...", network.label());
         let provider = Provider::<Http>::try_from(provider_url)?;
-        
-        // BSC Mainnet Chain ID
-        let chain_id = 56u64;
-        
+
+        let chain_id = network.chain_id();
+
         // Test connection
         let block_number = provider.get_block_number().await?;
-        println!("✅ Connected to BNB Smart Chain (BSC) Mainnet");
+        println!("✅ Connected to BNB Smart Chain (BSC) {}", network.label());
         println!("📊 Current block number: {}", block_number);
-        
+
         Ok(BlockchainService {
             provider,
             chain_id,
+            network,
         })
     }
     
@@ -146,21 +309,6 @@ impl BlockchainService {
         Ok((block_number.as_u64(), gas_price))
     }
     
-    fn generate_wallet(&self) -> Result<(String, String), Box<dyn std::error::Error + Send + Sync>> {
-        let secp = Secp256k1::new();
-        let mut rng = rand::thread_rng();
-        let mut secret_bytes = [0u8; 32];
-        rng.fill_bytes(&mut secret_bytes);
-        
-        let secret_key = SecretKey::from_slice(&secret_bytes)?;
-        let wallet = LocalWallet::from(secret_key).with_chain_id(self.chain_id);
-        
-        let address = format!("{:?}", wallet.address());
-        let private_key = hex::encode(wallet.private_key().to_bytes());
-        
-        Ok((address, private_key))
-    }
-    
     async fn get_balance(&self, address: &str) -> Result<U256, Box<dyn std::error::Error + Send + Sync>> {
         let addr = Address::from_str(address)?;
         let balance = self.provider.get_balance(addr, None).await?;
@@ -168,6 +316,690 @@ impl BlockchainService {
     }
 }
 
+/// BNB/USD price feed with a TTL cache, so a burst of `/api/stats` or
+/// `/api/rate` requests doesn't hammer the feed for every call.
+mod rate {
+    use super::Decimal;
+    use std::env;
+    use std::str::FromStr;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    const DEFAULT_PRICE_FEED_URL: &str =
+        "https://api.coingecko.com/api/v3/simple/price?ids=binancecoin&vs_currencies=usd";
+    const DEFAULT_TTL_SECS: u64 = 60;
+
+    pub struct Rate {
+        price_feed_url: String,
+        ttl: Duration,
+        cached: Mutex<Option<(Decimal, Instant)>>,
+    }
+
+    impl Rate {
+        pub fn new() -> Self {
+            let price_feed_url = env::var("BNB_PRICE_FEED_URL")
+                .unwrap_or_else(|_| DEFAULT_PRICE_FEED_URL.to_string());
+            let ttl = env::var("BNB_PRICE_TTL_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(Duration::from_secs(DEFAULT_TTL_SECS));
+
+            Rate { price_feed_url, ttl, cached: Mutex::new(None) }
+        }
+
+        /// Returns the current BNB/USD rate and how long ago it was fetched,
+        /// refreshing from the price feed only once the cached value goes
+        /// stale (or there isn't one yet).
+        pub async fn usd_per_bnb(&self) -> Result<(Decimal, Duration), Box<dyn std::error::Error + Send + Sync>> {
+            if let Some((rate, fetched_at)) = *self.cached.lock().unwrap() {
+                if fetched_at.elapsed() < self.ttl {
+                    return Ok((rate, fetched_at.elapsed()));
+                }
+            }
+
+            let rate = self.fetch().await?;
+            *self.cached.lock().unwrap() = Some((rate, Instant::now()));
+            Ok((rate, Duration::from_secs(0)))
+        }
+
+        async fn fetch(&self) -> Result<Decimal, Box<dyn std::error::Error + Send + Sync>> {
+            let body = reqwest::get(&self.price_feed_url).await?.text().await?;
+            let json: serde_json::Value = serde_json::from_str(&body)?;
+            let price = json["binancecoin"]["usd"]
+                .as_f64()
+                .ok_or("price feed response missing binancecoin.usd")?;
+
+            Decimal::from_str(&price.to_string())
+                .map_err(|e| format!("invalid price from feed: {}", e).into())
+        }
+    }
+}
+
+/// Binance public REST market data (spot price + 24h change), modeled on
+/// binance-rs's `Market::get_price`/`get_all_prices`. Responses are cached
+/// with a short TTL so a burst of `/api/system-metrics`/`/api/prices`
+/// requests doesn't hammer the exchange, and a failed fetch just means the
+/// caller gets an `Err` to degrade around rather than this module panicking.
+mod market {
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::env;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    const DEFAULT_TTL_SECS: u64 = 15;
+    const BINANCE_API_BASE: &str = "https://api.binance.com";
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Ticker {
+        pub symbol: String,
+        #[serde(rename = "priceUsd")]
+        pub price_usd: String,
+        #[serde(rename = "change24h")]
+        pub change_24h: String,
+    }
+
+    #[derive(Deserialize)]
+    struct PriceResponse {
+        price: String,
+    }
+
+    #[derive(Deserialize)]
+    struct Ticker24hr {
+        #[serde(rename = "priceChangePercent")]
+        price_change_percent: String,
+    }
+
+    pub struct Market {
+        ttl: Duration,
+        cache: Mutex<HashMap<String, (Ticker, Instant)>>,
+    }
+
+    impl Market {
+        pub fn new() -> Self {
+            let ttl = env::var("MARKET_DATA_TTL_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(Duration::from_secs(DEFAULT_TTL_SECS));
+
+            Market { ttl, cache: Mutex::new(HashMap::new()) }
+        }
+
+        /// Fetches (or returns a cached) ticker for a Binance symbol like
+        /// `BNBUSDT`, combining its spot price with its 24h change percent.
+        pub async fn ticker(&self, symbol: &str) -> Result<Ticker, Box<dyn std::error::Error + Send + Sync>> {
+            if let Some((ticker, fetched_at)) = self.cache.lock().unwrap().get(symbol) {
+                if fetched_at.elapsed() < self.ttl {
+                    return Ok(ticker.clone());
+                }
+            }
+
+            let ticker = self.fetch(symbol).await?;
+            self.cache.lock().unwrap().insert(symbol.to_string(), (ticker.clone(), Instant::now()));
+            Ok(ticker)
+        }
+
+        /// Fetches tickers for several symbols at once, following
+        /// `get_all_prices`'s shape; a symbol that fails to fetch is simply
+        /// omitted rather than failing the whole batch.
+        pub async fn tickers(&self, symbols: &[String]) -> Vec<Ticker> {
+            let mut out = Vec::with_capacity(symbols.len());
+            for symbol in symbols {
+                match self.ticker(symbol).await {
+                    Ok(ticker) => out.push(ticker),
+                    Err(e) => eprintln!("⚠️  Failed to fetch {} ticker: {}", symbol, e),
+                }
+            }
+            out
+        }
+
+        async fn fetch(&self, symbol: &str) -> Result<Ticker, Box<dyn std::error::Error + Send + Sync>> {
+            let price_url = format!("{}/api/v3/ticker/price?symbol={}", BINANCE_API_BASE, symbol);
+            let price: PriceResponse = reqwest::get(&price_url).await?.json().await?;
+
+            let stats_url = format!("{}/api/v3/ticker/24hr?symbol={}", BINANCE_API_BASE, symbol);
+            let stats: Ticker24hr = reqwest::get(&stats_url).await?.json().await?;
+
+            Ok(Ticker {
+                symbol: symbol.to_string(),
+                price_usd: price.price,
+                change_24h: stats.price_change_percent,
+            })
+        }
+    }
+}
+
+/// Transaction-risk ("know-your-transaction") counterparty screening,
+/// recasting the ic-btc-kyt canister's provenance-scoring idea for this
+/// crate's BSC wallet model: pull an address's recent transfers from
+/// BscScan, compute the share of inbound value that came from a
+/// blocklisted address, and bucket the result into a Low/Medium/High tier.
+/// The blocklist lives in the existing db_pool so it can be grown via an
+/// admin route, and per-address verdicts are cached with a timestamp so
+/// repeated lookups don't re-hit BscScan every time.
+mod risk {
+    use super::DbPool;
+    use super::Network;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::env;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    const DEFAULT_TTL_SECS: u64 = 300;
+    // Share of inbound value from a blocklisted address that bumps the tier.
+    const MEDIUM_THRESHOLD: f64 = 0.10;
+    const HIGH_THRESHOLD: f64 = 0.50;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Tier {
+        Low,
+        Medium,
+        High,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct FlaggedTransfer {
+        pub hash: String,
+        pub from: String,
+        pub to: String,
+        #[serde(rename = "valueWei")]
+        pub value_wei: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Verdict {
+        pub tier: Tier,
+        #[serde(rename = "flaggedValueRatio")]
+        pub flagged_value_ratio: f64,
+        pub contributing: Vec<FlaggedTransfer>,
+    }
+
+    #[derive(Deserialize)]
+    struct BscScanTx {
+        hash: String,
+        from: String,
+        to: String,
+        value: String,
+    }
+
+    #[derive(Deserialize)]
+    struct BscScanResponse {
+        status: String,
+        #[serde(default)]
+        result: serde_json::Value,
+    }
+
+    /// Caches per-address verdicts so repeated `/api/wallets/:address/risk`
+    /// lookups don't re-fetch and re-score the same address every time.
+    pub struct Screener {
+        ttl: Duration,
+        cache: Mutex<HashMap<String, (Verdict, Instant)>>,
+    }
+
+    impl Screener {
+        pub fn new() -> Self {
+            let ttl = env::var("RISK_VERDICT_TTL_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(Duration::from_secs(DEFAULT_TTL_SECS));
+
+            Screener { ttl, cache: Mutex::new(HashMap::new()) }
+        }
+
+        pub async fn screen(
+            &self,
+            db_pool: &DbPool,
+            network: Network,
+            address: &str,
+        ) -> Result<Verdict, Box<dyn std::error::Error + Send + Sync>> {
+            let address = address.to_lowercase();
+
+            if let Some((verdict, fetched_at)) = self.cache.lock().unwrap().get(&address) {
+                if fetched_at.elapsed() < self.ttl {
+                    return Ok(verdict.clone());
+                }
+            }
+
+            let verdict = self.score(db_pool, network, &address).await?;
+            self.cache.lock().unwrap().insert(address, (verdict.clone(), Instant::now()));
+            Ok(verdict)
+        }
+
+        async fn score(
+            &self,
+            db_pool: &DbPool,
+            network: Network,
+            address: &str,
+        ) -> Result<Verdict, Box<dyn std::error::Error + Send + Sync>> {
+            let blocklist = get_blocklist(db_pool)?;
+            let transfers = fetch_recent_transfers(network, address).await?;
+
+            let mut total_in: u128 = 0;
+            let mut flagged_in: u128 = 0;
+            let mut contributing = Vec::new();
+
+            for transfer in &transfers {
+                if transfer.to.to_lowercase() != address {
+                    continue;
+                }
+                let value: u128 = transfer.value.parse().unwrap_or(0);
+                total_in += value;
+
+                if blocklist.iter().any(|blocked| blocked.eq_ignore_ascii_case(&transfer.from)) {
+                    flagged_in += value;
+                    contributing.push(FlaggedTransfer {
+                        hash: transfer.hash.clone(),
+                        from: transfer.from.clone(),
+                        to: transfer.to.clone(),
+                        value_wei: transfer.value.clone(),
+                    });
+                }
+            }
+
+            let flagged_value_ratio = if total_in > 0 { flagged_in as f64 / total_in as f64 } else { 0.0 };
+            let tier = if flagged_value_ratio >= HIGH_THRESHOLD {
+                Tier::High
+            } else if flagged_value_ratio >= MEDIUM_THRESHOLD || !contributing.is_empty() {
+                Tier::Medium
+            } else {
+                Tier::Low
+            };
+
+            Ok(Verdict { tier, flagged_value_ratio, contributing })
+        }
+    }
+
+    async fn fetch_recent_transfers(
+        network: Network,
+        address: &str,
+    ) -> Result<Vec<BscScanTx>, Box<dyn std::error::Error + Send + Sync>> {
+        // `address` ultimately comes from a request path segment or body, so
+        // reject anything that isn't a real BSC address before it reaches the
+        // upstream URL - both to fail fast and so the query-param builder
+        // below never has to percent-encode anything but a validated hex string.
+        Address::from_str(address).map_err(|e| format!("invalid address {}: {}", address, e))?;
+
+        let host = match network {
+            Network::BscMainnet => "api.bscscan.com",
+            Network::BscTestnet => "api-testnet.bscscan.com",
+        };
+        let api_key = env::var("BSCSCAN_API_KEY").unwrap_or_default();
+        let url = reqwest::Url::parse_with_params(
+            &format!("https://{}/api", host),
+            &[
+                ("module", "account"),
+                ("action", "txlist"),
+                ("address", address),
+                ("sort", "desc"),
+                ("page", "1"),
+                ("offset", "50"),
+                ("apikey", api_key.as_str()),
+            ],
+        )?;
+
+        let body = reqwest::get(url).await?.text().await?;
+        let parsed: BscScanResponse = serde_json::from_str(&body)?;
+        if parsed.status != "1" {
+            // "0" covers both "no transactions found" and a throttled/invalid
+            // key response; either way there's nothing to score against.
+            return Ok(Vec::new());
+        }
+
+        Ok(serde_json::from_value(parsed.result).unwrap_or_default())
+    }
+
+    /// Reads the full blocklist. Backed by the existing db_pool rather than
+    /// an in-memory set, so it survives restarts and can be grown via the
+    /// admin route without redeploying.
+    pub fn get_blocklist(db_pool: &DbPool) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let conn = db_pool.get()?;
+        let rows = conn.query("SELECT address FROM risk_blocklist ORDER BY created_at DESC", &[])?;
+        Ok(rows.into_iter().map(|row| row.get("address")).collect())
+    }
+
+    pub fn add_to_blocklist(
+        db_pool: &DbPool,
+        address: &str,
+        reason: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let conn = db_pool.get()?;
+        conn.execute(
+            "INSERT INTO risk_blocklist (address, reason, created_at) VALUES ($1, $2, now()) \
+             ON CONFLICT (address) DO UPDATE SET reason = $2",
+            &[&address.to_lowercase(), &reason],
+        )?;
+        Ok(())
+    }
+}
+
+/// In-process counters feeding the Prometheus-format `GET /metrics` route.
+/// No metrics crate dependency: each request's route label, status code, and
+/// handling time are recorded here right after the route `match` produces a
+/// response, and `rpc_errors_total` is bumped from the handful of route arms
+/// that talk to the chain directly. Rendered as Prometheus text exposition
+/// format (https://prometheus.io/docs/instrumenting/exposition_formats/) on
+/// scrape, generalizing the ad hoc ✅/❌ stats dump already printed per route
+/// into a surface ops can actually graph and alert on.
+struct Metrics {
+    requests_total: Mutex<HashMap<(String, u16), u64>>,
+    request_duration_seconds_sum: Mutex<HashMap<String, f64>>,
+    rpc_errors_total: std::sync::atomic::AtomicU64,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Metrics {
+            requests_total: Mutex::new(HashMap::new()),
+            request_duration_seconds_sum: Mutex::new(HashMap::new()),
+            rpc_errors_total: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    fn record_request(&self, route: &str, status: u16, elapsed: Duration) {
+        *self.requests_total.lock().unwrap().entry((route.to_string(), status)).or_insert(0) += 1;
+        *self.request_duration_seconds_sum.lock().unwrap().entry(route.to_string()).or_insert(0.0) +=
+            elapsed.as_secs_f64();
+    }
+
+    fn record_rpc_error(&self) {
+        self.rpc_errors_total.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+// Maps a request onto the same route labels used in the match below, so
+// `/metrics` cardinality stays bounded regardless of dynamic path segments
+// (an address or tx hash) that actually came in.
+fn route_label(method: &Method, path_parts: &[&str]) -> &'static str {
+    match (method, path_parts) {
+        (Method::Get, ["api", "wallets"]) => "GET /api/wallets",
+        (Method::Post, ["api", "wallets", "bulk"]) => "POST /api/wallets/bulk",
+        (Method::Get, ["api", "wallets", "export"]) => "GET /api/wallets/export",
+        (Method::Post, ["api", "transactions"]) => "POST /api/transactions",
+        (Method::Get, ["api", "transactions", _, "trace"]) => "GET /api/transactions/:hash/trace",
+        (Method::Get, ["api", "activities"]) => "GET /api/activities",
+        (Method::Get, ["api", "system-metrics"]) => "GET /api/system-metrics",
+        (Method::Get, ["api", "prices"]) => "GET /api/prices",
+        (Method::Get, ["api", "stats"]) => "GET /api/stats",
+        (Method::Get, ["api", "rate"]) => "GET /api/rate",
+        (Method::Get, ["api", "wallets", _, "risk"]) => "GET /api/wallets/:address/risk",
+        (Method::Post, ["api", "screen"]) => "POST /api/screen",
+        (Method::Get, ["api", "admin", "blocklist"]) => "GET /api/admin/blocklist",
+        (Method::Post, ["api", "admin", "blocklist"]) => "POST /api/admin/blocklist",
+        (Method::Get, ["metrics"]) => "GET /metrics",
+        _ => "unmatched",
+    }
+}
+
+// Renders the counters above as Prometheus text exposition format, folding
+// in a couple of point-in-time gauges (wallet counts, chain height) read
+// fresh from the db/chain at scrape time rather than cached.
+fn render_prometheus_metrics(
+    metrics: &Metrics,
+    wallets_total: i64,
+    wallets_active: i64,
+    bsc_block_height: u64,
+    bsc_rpc_lag_seconds: f64,
+) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP wallets_total Total number of wallets known to this backend.\n");
+    out.push_str("# TYPE wallets_total gauge\n");
+    out.push_str(&format!("wallets_total {}\n", wallets_total));
+
+    out.push_str("# HELP wallets_active_total Number of wallets with status = 'active'.\n");
+    out.push_str("# TYPE wallets_active_total gauge\n");
+    out.push_str(&format!("wallets_active_total {}\n", wallets_active));
+
+    out.push_str("# HELP bsc_block_height Most recently observed BNB Smart Chain block number.\n");
+    out.push_str("# TYPE bsc_block_height gauge\n");
+    out.push_str(&format!("bsc_block_height {}\n", bsc_block_height));
+
+    out.push_str("# HELP bsc_rpc_lag_seconds Round-trip time of the last eth_blockNumber RPC call.\n");
+    out.push_str("# TYPE bsc_rpc_lag_seconds gauge\n");
+    out.push_str(&format!("bsc_rpc_lag_seconds {}\n", bsc_rpc_lag_seconds));
+
+    out.push_str("# HELP rpc_errors_total Cumulative failed blockchain RPC calls since process start.\n");
+    out.push_str("# TYPE rpc_errors_total counter\n");
+    out.push_str(&format!(
+        "rpc_errors_total {}\n",
+        metrics.rpc_errors_total.load(std::sync::atomic::Ordering::Relaxed)
+    ));
+
+    out.push_str("# HELP http_requests_total Total HTTP requests handled, by route and status code.\n");
+    out.push_str("# TYPE http_requests_total counter\n");
+    for ((route, status), count) in metrics.requests_total.lock().unwrap().iter() {
+        out.push_str(&format!(
+            "http_requests_total{{route=\"{}\",status=\"{}\"}} {}\n",
+            route, status, count
+        ));
+    }
+
+    out.push_str("# HELP http_request_duration_seconds_sum Cumulative request handling time, by route.\n");
+    out.push_str("# TYPE http_request_duration_seconds_sum counter\n");
+    for (route, sum) in metrics.request_duration_seconds_sum.lock().unwrap().iter() {
+        out.push_str(&format!(
+            "http_request_duration_seconds_sum{{route=\"{}\"}} {}\n",
+            route, sum
+        ));
+    }
+
+    out
+}
+
+// Converts a wei-denominated balance to USD using checked Decimal arithmetic
+// throughout (as the atomic-swap rate code does), avoiding the silent
+// precision loss and overflow you'd get converting 18-decimal wei through
+// floats. Returns None on any overflow rather than panicking.
+fn wei_to_usd(wei: &str, usd_per_bnb: Decimal) -> Option<Decimal> {
+    let wei = Decimal::from_str(wei).ok()?;
+    let bnb = wei.checked_div(Decimal::from(1_000_000_000_000_000_000u64))?;
+    bnb.checked_mul(usd_per_bnb)
+}
+
+/// Tracks the next nonce to use per sending address, seeded from the chain's
+/// pending transaction count the first time an address is seen and
+/// incremented locally after that. This lets several sends from the same
+/// wallet be submitted back-to-back within a batch without waiting for each
+/// one to be mined (and thus without racing each other for the same nonce).
+struct NonceManager {
+    next_nonce: Mutex<HashMap<Address, U256>>,
+}
+
+impl NonceManager {
+    fn new() -> Self {
+        NonceManager {
+            next_nonce: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn reserve_nonce(
+        &self,
+        provider: &Provider<Http>,
+        address: Address,
+    ) -> Result<U256, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(nonce) = self.take_cached(address) {
+            return Ok(nonce);
+        }
+
+        let seed = provider
+            .get_transaction_count(address, Some(BlockId::Number(BlockNumber::Pending)))
+            .await?;
+
+        let mut next_nonce = self.next_nonce.lock().unwrap();
+        let nonce = next_nonce.entry(address).or_insert(seed);
+        let reserved = *nonce;
+        *nonce += U256::one();
+        Ok(reserved)
+    }
+
+    fn take_cached(&self, address: Address) -> Option<U256> {
+        let mut next_nonce = self.next_nonce.lock().unwrap();
+        let nonce = next_nonce.get_mut(&address)?;
+        let reserved = *nonce;
+        *nonce += U256::one();
+        Some(reserved)
+    }
+}
+
+type HmacSha512 = Hmac<Sha512>;
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC-SHA512 accepts any key length");
+    mac.update(data);
+
+    let mut out = [0u8; 64];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
+
+// PBKDF2-HMAC-SHA512 with a 64-byte derived key. A single PBKDF2 block suffices
+// here because the HMAC-SHA512 output (64 bytes) already equals the requested
+// derived-key length, so there's no need for a general multi-block PBKDF2.
+fn pbkdf2_hmac_sha512_64(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 64] {
+    let mut block_salt = salt.to_vec();
+    block_salt.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut u = hmac_sha512(password, &block_salt);
+    let mut result = u;
+    for _ in 1..iterations {
+        u = hmac_sha512(password, &u);
+        for (r, b) in result.iter_mut().zip(u.iter()) {
+            *r ^= *b;
+        }
+    }
+    result
+}
+
+// BIP39: seed = PBKDF2-HMAC-SHA512(password = mnemonic, salt = "mnemonic", 2048 rounds).
+fn mnemonic_to_seed(mnemonic: &str) -> [u8; 64] {
+    pbkdf2_hmac_sha512_64(mnemonic.as_bytes(), b"mnemonic", 2048)
+}
+
+#[derive(Clone)]
+struct ExtendedKey {
+    key: SecretKey,
+    chain_code: [u8; 32],
+}
+
+// BIP32 master key: I = HMAC-SHA512(key = "Bitcoin seed", data = seed).
+fn master_key_from_seed(seed: &[u8]) -> Result<ExtendedKey, Box<dyn std::error::Error + Send + Sync>> {
+    let i = hmac_sha512(b"Bitcoin seed", seed);
+    let (il, ir) = i.split_at(32);
+
+    Ok(ExtendedKey {
+        key: SecretKey::from_slice(il)?,
+        chain_code: ir.try_into().expect("32-byte slice"),
+    })
+}
+
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+// BIP32 child key derivation (CKD). Hardened indices (>= HARDENED_OFFSET) mix the
+// parent *private* key into the HMAC input; normal indices mix in the parent
+// *public* key instead, so a normal child can be derived from an extended public
+// key alone. Either way, the child private key is the same secp256k1 scalar
+// addition: IL + kpar (mod n).
+fn derive_child_key(
+    secp: &Secp256k1<secp256k1::All>,
+    parent: &ExtendedKey,
+    index: u32,
+) -> Result<ExtendedKey, Box<dyn std::error::Error + Send + Sync>> {
+    let mut data = Vec::with_capacity(37);
+    if index >= HARDENED_OFFSET {
+        data.push(0u8);
+        data.extend_from_slice(&parent.key.secret_bytes());
+    } else {
+        let public_key = PublicKey::from_secret_key(secp, &parent.key);
+        data.extend_from_slice(&public_key.serialize());
+    }
+    data.extend_from_slice(&index.to_be_bytes());
+
+    let i = hmac_sha512(&parent.chain_code, &data);
+    let (il, ir) = i.split_at(32);
+
+    let tweak = Scalar::from_be_bytes(il.try_into().expect("32-byte slice"))?;
+    let child_key = parent.key.add_tweak(&tweak)?;
+
+    Ok(ExtendedKey {
+        key: child_key,
+        chain_code: ir.try_into().expect("32-byte slice"),
+    })
+}
+
+// BIP44 Ethereum "default account" prefix: m/44'/60'/0'/0. Wallet `i` lives at
+// m/44'/60'/0'/0/i.
+const HD_PATH_PREFIX: [u32; 4] = [
+    44 | HARDENED_OFFSET,
+    60 | HARDENED_OFFSET,
+    0 | HARDENED_OFFSET,
+    0,
+];
+
+/// Derives bulk wallets from a single BIP39 mnemonic along BIP32 path
+/// `m/44'/60'/0'/0/i`, so the entire set of wallets is recoverable from the
+/// mnemonic alone instead of requiring every private key to be backed up.
+struct HdWalletService {
+    secp: Secp256k1<secp256k1::All>,
+    mnemonic: String,
+    seed: [u8; 64],
+}
+
+impl HdWalletService {
+    /// Loads the mnemonic from `BIP39_MNEMONIC`, generating and printing a new
+    /// one if it isn't set. Note: a freshly generated mnemonic only lives for
+    /// this process's lifetime unless the operator copies it into the
+    /// environment, at which point restarts derive the same wallets again.
+    fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mnemonic = match env::var("BIP39_MNEMONIC") {
+            Ok(phrase) => Mnemonic::parse_in_normalized(Language::English, phrase.trim())?,
+            Err(_) => {
+                let mnemonic = Mnemonic::generate_in(Language::English, 12)?;
+                println!("🔑 BIP39_MNEMONIC not set; generated a new mnemonic for this run:");
+                println!("   {}", mnemonic);
+                println!("   Set BIP39_MNEMONIC to this value to recover these wallets later.");
+                mnemonic
+            }
+        };
+
+        let seed = mnemonic_to_seed(&mnemonic.to_string());
+
+        Ok(HdWalletService {
+            secp: Secp256k1::new(),
+            mnemonic: mnemonic.to_string(),
+            seed,
+        })
+    }
+
+    /// Derives the wallet at `m/44'/60'/0'/0/{index}`, returning its address and
+    /// private key (hex-encoded) exactly as [BlockchainService]'s old random
+    /// generator did, so callers don't need to change.
+    fn derive_wallet(
+        &self,
+        index: u32,
+        chain_id: u64,
+    ) -> Result<(String, String), Box<dyn std::error::Error + Send + Sync>> {
+        let mut key = master_key_from_seed(&self.seed)?;
+        for component in HD_PATH_PREFIX.iter().copied().chain(std::iter::once(index)) {
+            key = derive_child_key(&self.secp, &key, component)?;
+        }
+
+        let wallet = LocalWallet::from(key.key).with_chain_id(chain_id);
+        let address = format!("{:?}", wallet.address());
+        let private_key = hex::encode(wallet.private_key().to_bytes());
+
+        Ok((address, private_key))
+    }
+
+    fn mnemonic(&self) -> &str {
+        &self.mnemonic
+    }
+}
+
 fn init_db_pool() -> Result<DbPool, Box<dyn std::error::Error>> {
     let database_url = env::var("DATABASE_URL")
         .map_err(|_| "DATABASE_URL environment variable not set")?;
@@ -206,15 +1038,15 @@ fn get_wallets(db_pool: &DbPool) -> Result<Vec<Wallet>, Box<dyn std::error::Erro
     let conn = db_pool.get()?;
     
     let rows = conn.query(
-        "SELECT id, address, private_key, public_key, balance::text, status, label, last_activity, created_at FROM wallets ORDER BY created_at DESC",
+        "SELECT id, address, private_key, public_key, balance::text, status, label, last_activity, created_at, derivation_index, network FROM wallets ORDER BY created_at DESC",
         &[]
     )?;
-    
+
     let mut wallets = Vec::new();
     for row in rows {
         let last_activity: Option<DateTime<Utc>> = row.get("last_activity");
         let created_at: DateTime<Utc> = row.get("created_at");
-        
+
         let wallet = Wallet {
             id: row.get("id"),
             address: row.get("address"),
@@ -225,42 +1057,98 @@ fn get_wallets(db_pool: &DbPool) -> Result<Vec<Wallet>, Box<dyn std::error::Erro
             label: row.get("label"),
             last_activity: last_activity.map(|dt| dt.to_rfc3339()),
             created_at: created_at.to_rfc3339(),
+            derivation_index: row.get("derivation_index"),
+            network: row.get("network"),
         };
         wallets.push(wallet);
     }
-    
+
     Ok(wallets)
 }
 
+fn get_wallet_by_id(db_pool: &DbPool, id: &str) -> Result<Option<Wallet>, Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    let row = conn.query_opt(
+        "SELECT id, address, private_key, public_key, balance::text, status, label, last_activity, created_at, derivation_index, network FROM wallets WHERE id = $1",
+        &[&id],
+    )?;
+
+    Ok(row.map(|row| {
+        let last_activity: Option<DateTime<Utc>> = row.get("last_activity");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        Wallet {
+            id: row.get("id"),
+            address: row.get("address"),
+            private_key: row.get("private_key"),
+            public_key: row.get("public_key"),
+            balance: row.get("balance"),
+            status: row.get("status"),
+            label: row.get("label"),
+            last_activity: last_activity.map(|dt| dt.to_rfc3339()),
+            created_at: created_at.to_rfc3339(),
+            derivation_index: row.get("derivation_index"),
+            network: row.get("network"),
+        }
+    }))
+}
+
+// Finds the next unused HD derivation index, so bulk wallet creation keeps
+// advancing along the mnemonic's derivation path instead of ever reusing (and
+// thus re-deriving the same address as) an existing wallet. Must be called
+// inside a transaction that already holds `lock_wallets_for_index_allocation`,
+// otherwise two concurrent batches can read the same `MAX` and derive the same
+// indices (and therefore the same deterministic BIP44 addresses).
+fn next_derivation_index(tx: &mut postgres::Transaction<'_>) -> Result<i64, Box<dyn std::error::Error>> {
+    let max_index: Option<i64> = tx
+        .query_one("SELECT MAX(derivation_index) FROM wallets", &[])?
+        .get(0);
+
+    Ok(max_index.map(|i| i + 1).unwrap_or(0))
+}
+
+// Serializes concurrent index allocation: a `SHARE ROW EXCLUSIVE` lock lets
+// readers through but blocks other writers from taking the same lock, so the
+// `MAX(derivation_index)` read and the batch insert that follows behave as one
+// atomic unit across concurrent `POST /api/wallets/bulk` calls.
+fn lock_wallets_for_index_allocation(tx: &mut postgres::Transaction<'_>) -> Result<(), Box<dyn std::error::Error>> {
+    tx.execute("LOCK TABLE wallets IN SHARE ROW EXCLUSIVE MODE", &[])?;
+    Ok(())
+}
+
 async fn create_bulk_wallets(
     db_pool: &DbPool,
     blockchain: Option<&BlockchainService>,
+    hd_wallet: &HdWalletService,
     request: &BulkWalletRequest,
+    network: Network,
 ) -> Result<Vec<Wallet>, Box<dyn std::error::Error>> {
-    let conn = db_pool.get()?;
+    let mut conn = db_pool.get()?;
     let mut wallets = Vec::new();
-    
+
+    let chain_id = blockchain.map(|b| b.chain_id).unwrap_or_else(|| network.chain_id());
+
+    let mut tx = conn.transaction()?;
+    lock_wallets_for_index_allocation(&mut tx)?;
+    let start_index = next_derivation_index(&mut tx)?;
+
     for i in 0..request.count {
         let id = Uuid::new_v4().to_string();
-        
-        // Generate wallet (real BNB Smart Chain if available, otherwise mock)
-        let (address, private_key) = if let Some(blockchain_service) = blockchain {
-            blockchain_service.generate_wallet()?
-        } else {
-            // Generate mock wallet for degraded mode
-            let mock_address = format!("0x{}", hex::encode(&rand::random::<[u8; 20]>()));
-            let mock_private_key = hex::encode(&rand::random::<[u8; 32]>());
-            (mock_address, mock_private_key)
-        };
+        let derivation_index = start_index + i as i64;
+
+        // Derive deterministically from the service's HD seed, so the whole
+        // batch can be regenerated later from the mnemonic alone.
+        let (address, private_key) = hd_wallet.derive_wallet(derivation_index as u32, chain_id)?;
         let public_key = format!("0x{}", hex::encode(&rand::random::<[u8; 64]>())); // Simplified for demo
         let label = Some(format!("{} #{:03}", request.label_prefix, i + 1));
         let now = Utc::now();
-        
-        conn.execute(
-            "INSERT INTO wallets (id, address, private_key, public_key, balance, status, label, created_at) VALUES ($1, $2, $3, $4, $5::decimal, $6, $7, $8)",
-            &[&id, &address, &private_key, &public_key, &request.initial_balance, &"idle", &label, &now]
+
+        tx.execute(
+            "INSERT INTO wallets (id, address, private_key, public_key, balance, status, label, created_at, derivation_index, network) VALUES ($1, $2, $3, $4, $5::decimal, $6, $7, $8, $9, $10)",
+            &[&id, &address, &private_key, &public_key, &request.initial_balance, &"idle", &label, &now, &(derivation_index as i32), &network.as_str()]
         )?;
-        
+
         let wallet = Wallet {
             id,
             address,
@@ -271,17 +1159,22 @@ async fn create_bulk_wallets(
             label,
             last_activity: None,
             created_at: now.to_rfc3339(),
+            derivation_index: Some(derivation_index as i32),
+            network: network.as_str().to_string(),
         };
-        
+
         wallets.push(wallet);
     }
-    
+
+    tx.commit()?;
+
     // Create activity for bulk generation
-    let description = if blockchain.is_some() {
-        format!("Generated {} real BNB Smart Chain wallets", request.count)
-    } else {
-        format!("Generated {} mock wallets (blockchain unavailable)", request.count)
-    };
+    let description = format!(
+        "Generated {} HD wallets (indices {}-{})",
+        request.count,
+        start_index,
+        start_index + request.count as i64 - 1
+    );
     create_activity(
         db_pool,
         "bulk_wallet_generation",
@@ -289,23 +1182,90 @@ async fn create_bulk_wallets(
         "confirmed",
         None,
         None,
+        None,
+        network,
     )?;
-    
+
     Ok(wallets)
 }
 
-fn get_activities(db_pool: &DbPool) -> Result<Vec<Activity>, Box<dyn std::error::Error>> {
+const DEFAULT_ACTIVITIES_LIMIT: i64 = 50;
+const MAX_ACTIVITIES_LIMIT: i64 = 200;
+
+// Keyset-paginates over activities ordered by (created_at, id) — never by
+// offset, so a page stays stable even as new activities are inserted mid-
+// scroll. `before`/`after` are opaque cursors from a previous `ActivityPage`
+// (see [encode_cursor]/[decode_cursor]); at most one is honored per call,
+// with `before` taking priority since it expresses "go back to where I was".
+// `type_filter`/`wallet_filter` narrow the underlying SQL query directly
+// rather than filtering the page in memory.
+fn get_activities(
+    db_pool: &DbPool,
+    limit: i64,
+    after: Option<&str>,
+    before: Option<&str>,
+    type_filter: Option<&str>,
+    wallet_filter: Option<&str>,
+) -> Result<ActivityPage, Box<dyn std::error::Error>> {
     let conn = db_pool.get()?;
-    
-    let rows = conn.query(
-        "SELECT id, type, description, wallet_id, amount::text, status, transaction_hash, created_at FROM activities ORDER BY created_at DESC LIMIT 50",
-        &[]
-    )?;
-    
+
+    let limit = limit.clamp(1, MAX_ACTIVITIES_LIMIT);
+    let paging_before = before.and_then(decode_cursor);
+    let paging_after = if paging_before.is_none() { after.and_then(decode_cursor) } else { None };
+    // Paging `before` walks back toward newer rows, so that half of the
+    // keyset scan runs ascending and gets reversed back to newest-first below.
+    let reverse_order = paging_before.is_some();
+    let has_cursor = paging_before.is_some() || paging_after.is_some();
+
+    let mut clauses: Vec<String> = Vec::new();
+    let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+
+    if let Some(activity_type) = type_filter {
+        params.push(Box::new(activity_type.to_string()));
+        clauses.push(format!("type = ${}", params.len()));
+    }
+    if let Some(wallet_id) = wallet_filter {
+        params.push(Box::new(wallet_id.to_string()));
+        clauses.push(format!("wallet_id = ${}", params.len()));
+    }
+    if let Some((cursor_created_at, cursor_id)) = paging_before.or(paging_after) {
+        params.push(Box::new(cursor_created_at));
+        let ts_param = params.len();
+        params.push(Box::new(cursor_id));
+        let id_param = params.len();
+        let op = if reverse_order { ">" } else { "<" };
+        clauses.push(format!("(created_at, id) {} (${}, ${})", op, ts_param, id_param));
+    }
+
+    let where_clause = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", clauses.join(" AND "))
+    };
+    let order = if reverse_order { "ASC" } else { "DESC" };
+    // One row past the page is fetched to tell whether more lie beyond it.
+    params.push(Box::new(limit + 1));
+    let limit_param = params.len();
+
+    let sql = format!(
+        "SELECT id, type, description, wallet_id, amount::text, status, transaction_hash, gas_used, created_at, network \
+         FROM activities {} ORDER BY created_at {}, id {} LIMIT ${}",
+        where_clause, order, order, limit_param
+    );
+
+    let param_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = conn.query(sql.as_str(), &param_refs)?;
+
     let mut activities = Vec::new();
     for row in rows {
         let created_at: DateTime<Utc> = row.get("created_at");
-        
+        let network: String = row.get("network");
+        let transaction_hash: Option<String> = row.get("transaction_hash");
+        let explorer_url = transaction_hash.as_deref().map(|hash| match network.as_str() {
+            "testnet" => Network::BscTestnet.explorer_tx_url(hash),
+            _ => Network::BscMainnet.explorer_tx_url(hash),
+        });
+
         let activity = Activity {
             id: row.get("id"),
             activity_type: row.get("type"),
@@ -313,103 +1273,511 @@ fn get_activities(db_pool: &DbPool) -> Result<Vec<Activity>, Box<dyn std::error:
             wallet_id: row.get("wallet_id"),
             amount: row.get("amount"),
             status: row.get("status"),
-            transaction_hash: row.get("transaction_hash"),
+            transaction_hash,
+            gas_used: row.get("gas_used"),
             created_at: created_at.to_rfc3339(),
+            network,
+            explorer_url,
         };
         activities.push(activity);
     }
-    
-    Ok(activities)
+
+    let has_more = activities.len() as i64 > limit;
+    activities.truncate(limit as usize);
+
+    if reverse_order {
+        activities.reverse();
+    }
+
+    // `has_more` covers the scan direction; the opposite direction holds rows
+    // exactly when a cursor was given.
+    let (more_older, more_newer) = if reverse_order { (has_cursor, has_more) } else { (has_more, has_cursor) };
+    let next = if more_older { activities.last().map(|a| encode_cursor(&a.created_at, &a.id)) } else { None };
+    let prev = if more_newer { activities.first().map(|a| encode_cursor(&a.created_at, &a.id)) } else { None };
+
+    Ok(ActivityPage { activities, next, prev })
+}
+
+fn create_activity(
+    db_pool: &DbPool,
+    activity_type: &str,
+    description: &str,
+    status: &str,
+    wallet_id: Option<String>,
+    amount: Option<String>,
+    transaction_hash: Option<String>,
+    network: Network,
+) -> Result<Activity, Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    conn.execute(
+        "INSERT INTO activities (id, type, description, wallet_id, amount, status, transaction_hash, created_at, network) VALUES ($1, $2, $3, $4, $5::decimal, $6, $7, $8, $9)",
+        &[&id, &activity_type, &description, &wallet_id, &amount, &status, &transaction_hash, &now, &network.as_str()]
+    )?;
+
+    let explorer_url = transaction_hash.as_deref().map(|hash| network.explorer_tx_url(hash));
+
+    Ok(Activity {
+        id,
+        activity_type: activity_type.to_string(),
+        description: description.to_string(),
+        wallet_id,
+        amount,
+        status: status.to_string(),
+        transaction_hash,
+        gas_used: None,
+        created_at: now.to_rfc3339(),
+        network: network.as_str().to_string(),
+        explorer_url,
+    })
+}
+
+// Updates an activity's status (and, once known, gas used) after its
+// transaction receipt comes back from the chain. Used by the background
+// receipt poller to turn a `pending` send into `confirmed`/`failed`.
+fn update_activity_status(
+    db_pool: &DbPool,
+    id: &str,
+    status: &str,
+    gas_used: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    conn.execute(
+        "UPDATE activities SET status = $1, gas_used = $2 WHERE id = $3",
+        &[&status, &gas_used, &id],
+    )?;
+
+    Ok(())
+}
+
+// Returns (id, transaction_hash) for every activity still awaiting receipt
+// confirmation, for the background poller to check on.
+fn get_pending_transaction_activities(db_pool: &DbPool) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    let rows = conn.query(
+        "SELECT id, transaction_hash FROM activities WHERE status = 'pending' AND transaction_hash IS NOT NULL",
+        &[],
+    )?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.get("id"), row.get("transaction_hash")))
+        .collect())
+}
+
+async fn create_system_metrics(
+    db_pool: &DbPool,
+    blockchain: Option<&BlockchainService>,
+    market: &market::Market,
+    network: Network,
+) -> Result<SystemMetrics, Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    let id = Uuid::new_v4().to_string();
+
+    // Get network stats (real BNB Smart Chain if available, otherwise simulated)
+    let (block_number, gas_price_wei) = if let Some(blockchain_service) = blockchain {
+        blockchain_service.get_network_stats().await.unwrap_or((0, U256::from(5_000_000_000u64)))
+    } else {
+        // Simulated values for degraded mode
+        (rand::random::<u64>() % 10000000, U256::from(5_000_000_000u64 + (rand::random::<u64>() % 2_000_000_000)))
+    };
+
+    // Convert gas price from wei to gwei for display
+    let gas_price_gwei = gas_price_wei.as_u64() as f64 / 1_000_000_000.0;
+    let gas_price = format!("{:.2}", gas_price_gwei);
+
+    // Calculate latency based on block time
+    let latency = if block_number > 0 { 3 + (block_number % 10) as i32 } else { 12 };
+
+    let success_rate = format!("{:.1}", 98.5 + (rand::random::<f32>() * 1.5));
+    let tax_collected = "0.623".to_string();
+    let cpu_usage = 25 + ((rand::random::<u64>() % 35) as i32);
+    let memory_usage = 45 + ((rand::random::<u64>() % 25) as i32);
+    let now = Utc::now();
+
+    // Fold in live market data; an unreachable exchange just means these
+    // fields are omitted rather than the whole metrics snapshot failing.
+    let (bnb_price_usd, bnb_change_24h) = match market.ticker("BNBUSDT").await {
+        Ok(ticker) => (Some(ticker.price_usd), Some(ticker.change_24h)),
+        Err(e) => {
+            eprintln!("⚠️  Failed to fetch BNB market data: {}", e);
+            (None, None)
+        }
+    };
+
+    conn.execute(
+        "INSERT INTO system_metrics (id, latency, gas_price, success_rate, tax_collected, cpu_usage, memory_usage, bnb_price_usd, bnb_change_24h, created_at, network) VALUES ($1, $2, $3::decimal, $4::decimal, $5::decimal, $6, $7, $8, $9, $10, $11)",
+        &[&id, &latency, &gas_price, &success_rate, &tax_collected, &cpu_usage, &memory_usage, &bnb_price_usd, &bnb_change_24h, &now, &network.as_str()]
+    )?;
+
+    Ok(SystemMetrics {
+        id,
+        latency,
+        gas_price,
+        success_rate,
+        tax_collected,
+        cpu_usage,
+        memory_usage,
+        bnb_price_usd,
+        bnb_change_24h,
+        created_at: now.to_rfc3339(),
+        network: network.as_str().to_string(),
+    })
+}
+
+async fn get_wallet_stats(db_pool: &DbPool, rate: &rate::Rate, network: Network) -> Result<Stats, Box<dyn std::error::Error + Send + Sync>> {
+    let conn = db_pool.get()?;
+
+    let total_count: i64 = conn.query_one("SELECT COUNT(*) FROM wallets", &[])?.get(0);
+    let active_count: i64 = conn.query_one("SELECT COUNT(*) FROM wallets WHERE status = 'active'", &[])?.get(0);
+    let total_balance: Option<String> = conn.query_one("SELECT COALESCE(SUM(balance), 0)::text FROM wallets", &[])?.get(0);
+    let total_balance = total_balance.unwrap_or("0".to_string());
+
+    let total_balance_usd = match rate.usd_per_bnb().await {
+        Ok((usd_per_bnb, _age)) => wei_to_usd(&total_balance, usd_per_bnb).map(|usd| usd.round_dp(2).to_string()),
+        Err(e) => {
+            eprintln!("⚠️  Failed to fetch BNB/USD rate for stats: {}", e);
+            None
+        }
+    };
+
+    Ok(Stats {
+        total_wallets: total_count as usize,
+        active_wallets: active_count as usize,
+        total_balance,
+        total_balance_usd,
+        network: network.as_str().to_string(),
+    })
+}
+
+// Returns everything needed to regenerate every HD-derived wallet from
+// scratch: the mnemonic, the derivation path template, and the index range
+// that's actually in use.
+fn get_wallet_export(
+    db_pool: &DbPool,
+    hd_wallet: &HdWalletService,
+) -> Result<WalletExport, Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    let row = conn.query_one(
+        "SELECT MIN(derivation_index), MAX(derivation_index), COUNT(*) FROM wallets WHERE derivation_index IS NOT NULL",
+        &[],
+    )?;
+    let index_range_start: Option<i64> = row.get(0);
+    let index_range_end: Option<i64> = row.get(1);
+    let wallet_count: i64 = row.get(2);
+
+    Ok(WalletExport {
+        mnemonic: hd_wallet.mnemonic().to_string(),
+        derivation_path: "m/44'/60'/0'/0/i".to_string(),
+        index_range_start,
+        index_range_end,
+        wallet_count,
+    })
+}
+
+// Builds, signs, and submits a legacy BNB Smart Chain transfer from an
+// existing wallet, recording its hash as a `pending` activity. Gas price is
+// pulled fresh from the chain rather than negotiating EIP-1559 fee caps,
+// matching how [BlockchainService::get_gas_price] is already used elsewhere
+// in this service.
+async fn send_transaction(
+    db_pool: &DbPool,
+    blockchain: &BlockchainService,
+    nonce_manager: &NonceManager,
+    request: &TransactionSendRequest,
+) -> Result<Activity, Box<dyn std::error::Error + Send + Sync>> {
+    let sender = get_wallet_by_id(db_pool, &request.from_wallet_id)?
+        .ok_or("sender wallet not found")?;
+
+    let secret_key = SecretKey::from_slice(&hex::decode(
+        sender.private_key.trim_start_matches("0x"),
+    )?)?;
+    let wallet = LocalWallet::from(secret_key).with_chain_id(blockchain.chain_id);
+
+    let from = wallet.address();
+    let to = Address::from_str(&request.to_address)?;
+    let value = U256::from_dec_str(&request.amount_wei)?;
+
+    let nonce = nonce_manager.reserve_nonce(&blockchain.provider, from).await?;
+    let gas_price = blockchain.get_gas_price().await?;
+
+    let mut tx: TypedTransaction = TransactionRequest::new()
+        .from(from)
+        .to(to)
+        .value(value)
+        .nonce(nonce)
+        .gas_price(gas_price)
+        .chain_id(blockchain.chain_id)
+        .into();
+
+    let gas_limit = blockchain
+        .provider
+        .estimate_gas(&tx, None)
+        .await
+        .unwrap_or_else(|_| U256::from(21_000u64));
+    tx.set_gas(gas_limit);
+
+    let signature = wallet.sign_transaction(&tx).await?;
+    let raw_tx = tx.rlp_signed(&signature);
+
+    let pending_tx = blockchain.provider.send_raw_transaction(raw_tx).await?;
+    let tx_hash = *pending_tx;
+
+    let description = format!(
+        "Sent {} wei from {:?} to {}",
+        request.amount_wei, from, request.to_address
+    );
+    let activity = create_activity(
+        db_pool,
+        "transaction_send",
+        &description,
+        "pending",
+        Some(request.from_wallet_id.clone()),
+        Some(request.amount_wei.clone()),
+        Some(format!("{:?}", tx_hash)),
+        blockchain.network,
+    )?;
+
+    Ok(activity)
+}
+
+// Fetches the call-frame execution trace for a mined transaction via
+// `debug_traceTransaction`, reusing the existing JSON-RPC provider (ethers'
+// generic `request` escape hatch) rather than adding a tracing-specific
+// dependency. Lets an operator see exactly where a send reverted instead of
+// only seeing a `failed` activity row.
+async fn trace_transaction(
+    blockchain: &BlockchainService,
+    tx_hash: H256,
+) -> Result<CallFrame, Box<dyn std::error::Error + Send + Sync>> {
+    let params = (tx_hash, serde_json::json!({ "tracer": "callTracer" }));
+    let frame: CallFrame = blockchain.provider.request("debug_traceTransaction", params).await?;
+    Ok(frame)
+}
+
+// Polls every `pending` send's transaction hash for a mined receipt, turning
+// it into `confirmed`/`failed` and stamping the gas it used. Runs as a
+// background task for the lifetime of the process rather than being awaited
+// by the request that submitted the transaction, since confirmation can take
+// far longer than an HTTP request should block for.
+async fn poll_pending_transactions(
+    db_pool: &DbPool,
+    blockchain: &BlockchainService,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    for (id, hash) in get_pending_transaction_activities(db_pool)? {
+        let tx_hash = match H256::from_str(&hash) {
+            Ok(hash) => hash,
+            Err(_) => continue,
+        };
+
+        match blockchain.provider.get_transaction_receipt(tx_hash).await {
+            Ok(Some(receipt)) => {
+                let status = if receipt.status == Some(U64::one()) { "confirmed" } else { "failed" };
+                let gas_used = receipt.gas_used.map(|g| g.to_string());
+                update_activity_status(db_pool, &id, status, gas_used)?;
+                println!("✅ Transaction {} {}", hash, status);
+            }
+            Ok(None) => {} // not yet mined
+            Err(e) => eprintln!("⚠️  Failed to fetch receipt for {}: {}", hash, e),
+        }
+    }
+
+    Ok(())
+}
+
+const DEFAULT_BALANCE_SYNC_INTERVAL_SECS: u64 = 60;
+const BALANCE_SYNC_BATCH_SIZE: usize = 10;
+const BALANCE_SYNC_BATCH_DELAY: Duration = Duration::from_millis(250);
+
+fn balance_sync_interval() -> Duration {
+    let secs = env::var("BALANCE_SYNC_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_BALANCE_SYNC_INTERVAL_SECS);
+    Duration::from_secs(secs)
+}
+
+// Refreshes every wallet's on-chain balance, fetched in small batches (with a
+// short pause between batches) rather than firing one `get_balance` request
+// per wallet all at once, to stay within Quicknode's rate limits.
+async fn sync_wallet_balances(
+    db_pool: &DbPool,
+    blockchain: &BlockchainService,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let wallets = get_wallets(db_pool)?;
+
+    for batch in wallets.chunks(BALANCE_SYNC_BATCH_SIZE) {
+        for wallet in batch {
+            match blockchain.get_balance(&wallet.address).await {
+                Ok(balance) => {
+                    let balance = balance.to_string();
+                    if balance != wallet.balance {
+                        update_wallet_balance(db_pool, &wallet.id, &balance)?;
+                        println!("💰 Synced balance for {}: {} -> {} wei", wallet.address, wallet.balance, balance);
+                    }
+                }
+                Err(e) => eprintln!("⚠️  Failed to fetch balance for {}: {}", wallet.address, e),
+            }
+        }
+        tokio::time::sleep(BALANCE_SYNC_BATCH_DELAY).await;
+    }
+
+    Ok(())
+}
+
+fn update_wallet_balance(db_pool: &DbPool, id: &str, balance: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = db_pool.get()?;
+
+    conn.execute(
+        "UPDATE wallets SET balance = $1::decimal, last_activity = now() WHERE id = $2",
+        &[&balance, &id],
+    )?;
+
+    Ok(())
+}
+
+// Periodic refresh after the startup sync has already run. Like BDK's
+// electrum-backed wallets doing a full sync before serving, the first sync
+// happens synchronously in `main` before the HTTP server starts; this worker
+// just keeps it current afterward.
+fn spawn_balance_sync_worker(db_pool: Arc<DbPool>, blockchain: Arc<BlockchainService>) {
+    let interval_duration = balance_sync_interval();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(interval_duration);
+        interval.tick().await; // the immediate first tick; startup sync already covered this
+        loop {
+            interval.tick().await;
+            if let Err(e) = sync_wallet_balances(&db_pool, &blockchain).await {
+                eprintln!("⚠️  Balance sync failed: {}", e);
+            }
+        }
+    });
+}
+
+fn spawn_receipt_poller(db_pool: Arc<DbPool>, blockchain: Arc<BlockchainService>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            if let Err(e) = poll_pending_transactions(&db_pool, &blockchain).await {
+                eprintln!("⚠️  Receipt poller encountered an error: {}", e);
+            }
+        }
+    });
+}
+
+const SYSTEM_METRICS_STREAM_INTERVAL_SECS: u64 = 5;
+
+// Keeps the HTTP connection open and pushes a fresh `create_system_metrics`
+// snapshot as a `text/event-stream` frame every
+// `SYSTEM_METRICS_STREAM_INTERVAL_SECS` seconds, so dashboards can subscribe
+// instead of polling `/api/system-metrics`. This bypasses the normal
+// match-and-respond flow, since tiny_http only lets a handler send one
+// `Response` per request: streaming means writing chunks directly to the
+// connection through `Request::into_writer` instead, driven by a tokio
+// interval on the runtime rather than blocking the server's request thread.
+fn stream_system_metrics(
+    request: Request,
+    db_pool: Arc<DbPool>,
+    blockchain: Option<Arc<BlockchainService>>,
+    market: Arc<market::Market>,
+    network: Network,
+    rt_handle: &tokio::runtime::Handle,
+) {
+    let writer = request.into_writer();
+
+    let headers = concat!(
+        "HTTP/1.1 200 OK\r\n",
+        "Content-Type: text/event-stream\r\n",
+        "Cache-Control: no-cache\r\n",
+        "Connection: keep-alive\r\n",
+        "Transfer-Encoding: chunked\r\n",
+        "Access-Control-Allow-Origin: *\r\n",
+        "\r\n",
+    );
+    if writer.lock().unwrap().write_all(headers.as_bytes()).is_err() {
+        return;
+    }
+
+    rt_handle.spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(SYSTEM_METRICS_STREAM_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+
+            let metrics = match create_system_metrics(&db_pool, blockchain.as_deref(), &market, network).await {
+                Ok(metrics) => metrics,
+                Err(e) => {
+                    eprintln!("⚠️  Error generating streamed system metrics: {}", e);
+                    continue;
+                }
+            };
+
+            let frame = format!("data: {}\n\n", serde_json::to_string(&metrics).unwrap_or_default());
+            let chunk = format!("{:x}\r\n{}\r\n", frame.len(), frame);
+
+            // A write error here means the client went away; stop pushing
+            // frames instead of looping forever against a dead connection.
+            let mut writer = writer.lock().unwrap();
+            if writer.write_all(chunk.as_bytes()).is_err() || writer.flush().is_err() {
+                println!("🔌 SSE client disconnected from /api/system-metrics/stream");
+                break;
+            }
+        }
+    });
 }
 
-fn create_activity(
-    db_pool: &DbPool,
-    activity_type: &str,
-    description: &str,
-    status: &str,
-    wallet_id: Option<String>,
-    amount: Option<String>,
-) -> Result<Activity, Box<dyn std::error::Error>> {
-    let conn = db_pool.get()?;
-    
-    let id = Uuid::new_v4().to_string();
-    let now = Utc::now();
-    
-    conn.execute(
-        "INSERT INTO activities (id, type, description, wallet_id, amount, status, transaction_hash, created_at) VALUES ($1, $2, $3, $4, $5::decimal, $6, $7, $8)",
-        &[&id, &activity_type, &description, &wallet_id, &amount, &status, &None::<String>, &now]
-    )?;
-    
-    Ok(Activity {
-        id,
-        activity_type: activity_type.to_string(),
-        description: description.to_string(),
-        wallet_id,
-        amount,
-        status: status.to_string(),
-        transaction_hash: None,
-        created_at: now.to_rfc3339(),
+// Looks up a single key in a raw (already split-off) query string like
+// `symbols=BNBUSDT,ETHUSDT&limit=10`. No URL-decoding: every query parameter
+// this service reads today is plain alphanumerics/commas.
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=')?;
+        (k == key).then_some(v)
     })
 }
 
-async fn create_system_metrics(
-    db_pool: &DbPool,
-    blockchain: Option<&BlockchainService>,
-) -> Result<SystemMetrics, Box<dyn std::error::Error>> {
-    let conn = db_pool.get()?;
-    
-    let id = Uuid::new_v4().to_string();
-    
-    // Get network stats (real BNB Smart Chain if available, otherwise simulated)
-    let (block_number, gas_price_wei) = if let Some(blockchain_service) = blockchain {
-        blockchain_service.get_network_stats().await.unwrap_or((0, U256::from(5_000_000_000u64)))
-    } else {
-        // Simulated values for degraded mode
-        (rand::random::<u64>() % 10000000, U256::from(5_000_000_000u64 + (rand::random::<u64>() % 2_000_000_000)))
-    };
-    
-    // Convert gas price from wei to gwei for display
-    let gas_price_gwei = gas_price_wei.as_u64() as f64 / 1_000_000_000.0;
-    let gas_price = format!("{:.2}", gas_price_gwei);
-    
-    // Calculate latency based on block time
-    let latency = if block_number > 0 { 3 + (block_number % 10) as i32 } else { 12 };
-    
-    let success_rate = format!("{:.1}", 98.5 + (rand::random::<f32>() * 1.5));
-    let tax_collected = "0.623".to_string();
-    let cpu_usage = 25 + ((rand::random::<u64>() % 35) as i32);
-    let memory_usage = 45 + ((rand::random::<u64>() % 25) as i32);
-    let now = Utc::now();
-    
-    conn.execute(
-        "INSERT INTO system_metrics (id, latency, gas_price, success_rate, tax_collected, cpu_usage, memory_usage, created_at) VALUES ($1, $2, $3::decimal, $4::decimal, $5::decimal, $6, $7, $8)",
-        &[&id, &latency, &gas_price, &success_rate, &tax_collected, &cpu_usage, &memory_usage, &now]
-    )?;
-    
-    Ok(SystemMetrics {
-        id,
-        latency,
-        gas_price,
-        success_rate,
-        tax_collected,
-        cpu_usage,
-        memory_usage,
-        created_at: now.to_rfc3339(),
-    })
+// Opaque keyset-pagination cursor for `/api/activities`: hex-encodes
+// `<rfc3339 created_at>|<id>` so it round-trips exactly without exposing the
+// underlying columns to the client, while still letting the query's WHERE
+// clause key off (created_at, id) instead of an offset that can skip or
+// repeat rows as new activities arrive mid-scroll.
+fn encode_cursor(created_at: &str, id: &str) -> String {
+    hex::encode(format!("{}|{}", created_at, id))
 }
 
-fn get_wallet_stats(db_pool: &DbPool) -> Result<Stats, Box<dyn std::error::Error>> {
-    let conn = db_pool.get()?;
-    
-    let total_count: i64 = conn.query_one("SELECT COUNT(*) FROM wallets", &[])?.get(0);
-    let active_count: i64 = conn.query_one("SELECT COUNT(*) FROM wallets WHERE status = 'active'", &[])?.get(0);
-    let total_balance: Option<String> = conn.query_one("SELECT COALESCE(SUM(balance), 0)::text FROM wallets", &[])?.get(0);
-    
-    Ok(Stats {
-        total_wallets: total_count as usize,
-        active_wallets: active_count as usize,
-        total_balance: total_balance.unwrap_or("0".to_string()),
-    })
+fn decode_cursor(cursor: &str) -> Option<(DateTime<Utc>, String)> {
+    let bytes = hex::decode(cursor).ok()?;
+    let text = String::from_utf8(bytes).ok()?;
+    let (created_at, id) = text.split_once('|')?;
+    let created_at = DateTime::parse_from_rfc3339(created_at).ok()?.with_timezone(&Utc);
+    Some((created_at, id.to_string()))
+}
+
+// Checks a request's `Authorization: Bearer <token>` header against the
+// `WALLET_EXPORT_ADMIN_TOKEN` env var. This is a single shared secret, not a
+// real auth system - there isn't one anywhere in this service yet - but it's
+// the minimum needed to keep `/api/wallets/export` (which hands back the
+// mnemonic for every derived wallet) from being a bare unauthenticated GET.
+// Fails closed: with no token configured, the endpoint refuses every request.
+fn admin_token_authorized(request: &Request) -> bool {
+    let configured = match env::var("WALLET_EXPORT_ADMIN_TOKEN") {
+        Ok(token) if !token.is_empty() => token,
+        _ => return false,
+    };
+
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Authorization"))
+        .and_then(|h| h.value.as_str().strip_prefix("Bearer "))
+        .is_some_and(|supplied| supplied == configured)
 }
 
 fn handle_cors(request: &Request) -> Option<Response<std::io::Cursor<Vec<u8>>>> {
@@ -426,11 +1794,20 @@ fn handle_cors(request: &Request) -> Option<Response<std::io::Cursor<Vec<u8>>>>
 }
 
 fn add_cors_headers(response: Response<std::io::Cursor<Vec<u8>>>) -> Response<std::io::Cursor<Vec<u8>>> {
-    response
+    // Every route returns JSON except /metrics, which sets its own
+    // Prometheus Content-Type before this runs — don't stomp on it.
+    let has_content_type = response.headers().iter().any(|h| h.field.equiv("Content-Type"));
+
+    let response = response
         .with_header(Header::from_bytes(&b"Access-Control-Allow-Origin"[..], &b"*"[..]).unwrap())
         .with_header(Header::from_bytes(&b"Access-Control-Allow-Methods"[..], &b"GET, POST, PUT, DELETE, PATCH, OPTIONS"[..]).unwrap())
-        .with_header(Header::from_bytes(&b"Access-Control-Allow-Headers"[..], &b"Content-Type, Authorization"[..]).unwrap())
-        .with_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap())
+        .with_header(Header::from_bytes(&b"Access-Control-Allow-Headers"[..], &b"Content-Type, Authorization"[..]).unwrap());
+
+    if has_content_type {
+        response
+    } else {
+        response.with_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap())
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -439,9 +1816,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .enable_all()
         .build()?;
     println!("🚀 Starting production-ready Rust backend with BNB Smart Chain integration...");
-    
+
+    // Which chain this instance talks to, fixed for the whole process; see
+    // `BSC_NETWORK` in the Network doc comment.
+    let network = Network::from_env();
+    println!("📡 Configured network: BSC {}", network.label());
+
     // All async initialization happens on the runtime
-    let (db_pool, blockchain) = rt.block_on(async {
+    let (db_pool, blockchain, hd_wallet, nonce_manager, rate, market, risk_screener, metrics) = rt.block_on(async {
     
         // Initialize thread-safe database connection pool
         let db_pool = match init_db_pool() {
@@ -476,7 +1858,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         
         // Initialize Quicknode BNB Smart Chain blockchain service (optional)
-        let blockchain = match BlockchainService::new().await {
+        let blockchain = match BlockchainService::new(network).await {
             Ok(service) => {
                 println!("✅ Quicknode BNB Smart Chain integration ready");
                 Some(Arc::new(service))
@@ -490,9 +1872,48 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         };
         
-        (db_pool, blockchain)
+        // Initialize the HD wallet service. Unlike the blockchain connection,
+        // this isn't optional: every wallet this backend creates is now
+        // derived from it, so there's no degraded mode for it to fall back to.
+        let hd_wallet = match HdWalletService::new() {
+            Ok(service) => {
+                println!("✅ HD wallet service ready (derivation path m/44'/60'/0'/0/i)");
+                Arc::new(service)
+            }
+            Err(e) => {
+                eprintln!("❌ Failed to initialize HD wallet service: {}", e);
+                panic!("HD wallet service required for wallet generation");
+            }
+        };
+
+        // Per-address nonce tracking for outgoing transactions, and a
+        // background task that reconciles pending sends against on-chain
+        // receipts. Both only matter when there's a chain to talk to.
+        let nonce_manager = Arc::new(NonceManager::new());
+        let rate = Arc::new(rate::Rate::new());
+        let market = Arc::new(market::Market::new());
+        let risk_screener = Arc::new(risk::Screener::new());
+        let metrics = Arc::new(Metrics::new());
+        if let Some(blockchain) = &blockchain {
+            spawn_receipt_poller(Arc::clone(&db_pool), Arc::clone(blockchain));
+            println!("✅ Transaction receipt poller running (5s interval)");
+
+            // Full sync before serving, so /api/stats reflects true chain
+            // state from the first request onward.
+            println!("🔄 Performing initial wallet balance sync...");
+            match sync_wallet_balances(&db_pool, blockchain).await {
+                Ok(()) => println!("✅ Initial wallet balance sync complete"),
+                Err(e) => eprintln!("⚠️  Initial wallet balance sync failed: {}", e),
+            }
+
+            let sync_interval = balance_sync_interval();
+            spawn_balance_sync_worker(Arc::clone(&db_pool), Arc::clone(blockchain));
+            println!("✅ Balance sync worker running ({}s interval)", sync_interval.as_secs());
+        }
+
+        (db_pool, blockchain, hd_wallet, nonce_manager, rate, market, risk_screener, metrics)
     });
-    
+
     // Move HTTP server to blocking thread to avoid runtime conflicts
     let rt_handle = rt.handle().clone();
     rt.block_on(async {
@@ -513,17 +1934,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("📊 API endpoints with real BNB Smart Chain integration:");
             println!("   GET  /api/wallets         - Retrieve all wallets (real BSC addresses)");
             println!("   POST /api/wallets/bulk    - Create multiple real BSC wallets");
-            println!("   GET  /api/activities      - Retrieve recent activities");
+            println!("   GET  /api/wallets/export  - Export mnemonic + derivation index range (requires Authorization: Bearer <WALLET_EXPORT_ADMIN_TOKEN>)");
+            println!("   POST /api/transactions    - Send BNB from a wallet, tracked to confirmation");
+            println!("   GET  /api/transactions/:hash/trace - debug_traceTransaction call-frame trace");
+            println!("   GET  /api/activities      - Paginated activities (?limit=&before=&after=&type=&wallet=)");
             println!("   GET  /api/system-metrics  - Get real-time BSC network metrics");
-            println!("   GET  /api/stats           - Get wallet statistics");
+            println!("   GET  /api/system-metrics/stream - SSE stream of live system metrics");
+            println!("   GET  /api/prices          - Get live Binance spot prices (?symbols=BNBUSDT,...)");
+            println!("   GET  /api/stats           - Get wallet statistics (incl. totalBalanceUsd)");
+            println!("   GET  /api/rate            - Get the cached BNB/USD rate and its age");
+            println!("   GET  /api/wallets/:address/risk - KYT counterparty risk tier for an address");
+            println!("   POST /api/screen          - Batch KYT risk screening ({{\"addresses\": [...]}})");
+            println!("   GET  /api/admin/blocklist - List blocklisted addresses");
+            println!("   POST /api/admin/blocklist - Add/update a blocklisted address");
+            println!("   GET  /metrics             - Prometheus text exposition format");
             println!("✨ Production-ready Rust backend with Quicknode BNB Smart Chain integration ready!");
             println!("🔐 Thread-safe concurrent request handling enabled");
-            println!("⛓️  Real blockchain connectivity via Quicknode mainnet");
+            println!("⛓️  Real blockchain connectivity via Quicknode ({})", network.label());
 
             // Handle requests in a blocking loop (tiny_http is sync)
             for mut request in server.incoming_requests() {
                 let db_pool = Arc::clone(&db_pool);
                 let blockchain = blockchain.clone();
+                let hd_wallet = Arc::clone(&hd_wallet);
+                let nonce_manager = Arc::clone(&nonce_manager);
+                let rate = Arc::clone(&rate);
+                let market = Arc::clone(&market);
+                let risk_screener = Arc::clone(&risk_screener);
+                let metrics = Arc::clone(&metrics);
+                let network = network;
                 let rt_handle = rt_handle.clone();
                 
                 // Handle CORS preflight
@@ -534,10 +1973,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let method = request.method().clone();
                 let url = request.url().to_string();
-                let path_parts: Vec<&str> = url.trim_start_matches('/').split('/').collect();
+                let (path, query) = match url.split_once('?') {
+                    Some((path, query)) => (path, Some(query.to_string())),
+                    None => (url.as_str(), None),
+                };
+                let path_parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
 
                 println!("📥 {} {}", method, url);
 
+                // GET /api/system-metrics/stream — long-lived SSE connection, handled
+                // separately since it writes its own response directly through tiny_http's
+                // `Writer` instead of returning a single `Response` like every other route.
+                if method == Method::Get && path_parts.as_slice() == ["api", "system-metrics", "stream"] {
+                    stream_system_metrics(request, Arc::clone(&db_pool), blockchain.clone(), Arc::clone(&market), network, &rt_handle);
+                    continue;
+                }
+
+                let request_start = Instant::now();
+                let route = route_label(&method, path_parts.as_slice());
+
                 let response = match (method.clone(), path_parts.as_slice()) {
             // GET /api/wallets
             (Method::Get, ["api", "wallets"]) => {
@@ -565,10 +2019,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         let (tx, rx) = oneshot::channel();
                         let db_pool_clone = Arc::clone(&db_pool);
                         let blockchain_clone = blockchain.clone();
+                        let hd_wallet_clone = Arc::clone(&hd_wallet);
                         let bulk_request_clone = bulk_request.clone();
-                        
+
                         rt_handle.spawn(async move {
-                            let result = create_bulk_wallets(&db_pool_clone, blockchain_clone.as_deref(), &bulk_request_clone).await;
+                            let result = create_bulk_wallets(&db_pool_clone, blockchain_clone.as_deref(), &hd_wallet_clone, &bulk_request_clone, network).await;
                             let _ = tx.send(result);
                         });
                         
@@ -597,12 +2052,145 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
 
-            // GET /api/activities
+            // GET /api/wallets/export — hands back the HD mnemonic, so it's gated
+            // behind a bearer token (see `admin_token_authorized`) rather than
+            // open to anyone who can reach this server.
+            (Method::Get, ["api", "wallets", "export"]) => {
+                if !admin_token_authorized(&request) {
+                    eprintln!("❌ Unauthorized wallet export attempt");
+                    Response::from_string(r#"{"error": "Unauthorized"}"#).with_status_code(401)
+                } else {
+                    match get_wallet_export(&db_pool, &hd_wallet) {
+                        Ok(export) => {
+                            println!("✅ Exported HD wallet recovery info ({} wallets)", export.wallet_count);
+                            let json = serde_json::to_string(&export).unwrap_or_default();
+                            Response::from_string(json)
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Error exporting wallet info: {}", e);
+                            Response::from_string(r#"{"error": "Failed to export wallet info"}"#).with_status_code(500)
+                        }
+                    }
+                }
+            }
+
+            // POST /api/transactions
+            (Method::Post, ["api", "transactions"]) => {
+                let mut body = String::new();
+                if request.as_reader().read_to_string(&mut body).is_ok() {
+                    if let Ok(send_request) = serde_json::from_str::<TransactionSendRequest>(&body) {
+                        match &blockchain {
+                            Some(blockchain) => {
+                                println!("📝 Sending {} wei from wallet {} to {}", send_request.amount_wei, send_request.from_wallet_id, send_request.to_address);
+
+                                let (tx, rx) = oneshot::channel();
+                                let db_pool_clone = Arc::clone(&db_pool);
+                                let blockchain_clone = Arc::clone(blockchain);
+                                let nonce_manager_clone = Arc::clone(&nonce_manager);
+                                let send_request_clone = send_request.clone();
+
+                                rt_handle.spawn(async move {
+                                    let result = send_transaction(&db_pool_clone, &blockchain_clone, &nonce_manager_clone, &send_request_clone).await;
+                                    let _ = tx.send(result);
+                                });
+
+                                match rx.blocking_recv() {
+                                    Ok(Ok(activity)) => {
+                                        println!("✅ Submitted transaction {}", activity.transaction_hash.as_deref().unwrap_or("?"));
+                                        let json = serde_json::to_string(&activity).unwrap_or_default();
+                                        Response::from_string(json).with_status_code(201)
+                                    }
+                                    Ok(Err(e)) => {
+                                        eprintln!("❌ Error sending transaction: {}", e);
+                                        metrics.record_rpc_error();
+                                        Response::from_string(r#"{"error": "Failed to send transaction"}"#).with_status_code(500)
+                                    }
+                                    Err(_) => {
+                                        eprintln!("❌ Channel communication failed");
+                                        Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                                    }
+                                }
+                            }
+                            None => {
+                                eprintln!("❌ Cannot send transaction: running in degraded mode without blockchain connectivity");
+                                Response::from_string(r#"{"error": "Blockchain connectivity required to send transactions"}"#).with_status_code(503)
+                            }
+                        }
+                    } else {
+                        eprintln!("❌ Invalid request body format");
+                        Response::from_string(r#"{"error": "Invalid request body"}"#).with_status_code(400)
+                    }
+                } else {
+                    eprintln!("❌ Failed to read request body");
+                    Response::from_string(r#"{"error": "Failed to read request body"}"#).with_status_code(400)
+                }
+            }
+
+            // GET /api/transactions/:hash/trace
+            (Method::Get, ["api", "transactions", hash, "trace"]) => {
+                match (&blockchain, H256::from_str(hash)) {
+                    (Some(blockchain), Ok(tx_hash)) => {
+                        println!("🔍 Tracing transaction {}", hash);
+
+                        let (tx, rx) = oneshot::channel();
+                        let blockchain_clone = Arc::clone(blockchain);
+
+                        rt_handle.spawn(async move {
+                            let result = trace_transaction(&blockchain_clone, tx_hash).await;
+                            let _ = tx.send(result);
+                        });
+
+                        match rx.blocking_recv() {
+                            Ok(Ok(frame)) => {
+                                println!("✅ Retrieved trace for transaction {}", hash);
+                                let json = serde_json::to_string(&frame).unwrap_or_default();
+                                Response::from_string(json)
+                            }
+                            Ok(Err(e)) => {
+                                eprintln!("❌ Error tracing transaction {}: {}", hash, e);
+                                metrics.record_rpc_error();
+                                Response::from_string(r#"{"error": "Failed to trace transaction"}"#).with_status_code(500)
+                            }
+                            Err(_) => {
+                                eprintln!("❌ Channel communication failed");
+                                Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                            }
+                        }
+                    }
+                    (None, _) => {
+                        eprintln!("❌ Cannot trace transaction: running in degraded mode without blockchain connectivity");
+                        Response::from_string(r#"{"error": "Blockchain connectivity required to trace transactions"}"#).with_status_code(503)
+                    }
+                    (_, Err(_)) => {
+                        eprintln!("❌ Invalid transaction hash: {}", hash);
+                        Response::from_string(r#"{"error": "Invalid transaction hash"}"#).with_status_code(400)
+                    }
+                }
+            }
+
+            // GET /api/activities?limit=&before=&after=&type=&wallet=
             (Method::Get, ["api", "activities"]) => {
-                match get_activities(&db_pool) {
-                    Ok(activities) => {
-                        println!("✅ Retrieved {} activities", activities.len());
-                        let json = serde_json::to_string(&activities).unwrap_or_default();
+                let limit = query
+                    .as_deref()
+                    .and_then(|q| query_param(q, "limit"))
+                    .and_then(|l| l.parse().ok())
+                    .unwrap_or(DEFAULT_ACTIVITIES_LIMIT);
+                let before = query.as_deref().and_then(|q| query_param(q, "before")).map(|s| s.to_string());
+                let after = query.as_deref().and_then(|q| query_param(q, "after")).map(|s| s.to_string());
+                let type_filter = query.as_deref().and_then(|q| query_param(q, "type")).map(|s| s.to_string());
+                let wallet_filter = query.as_deref().and_then(|q| query_param(q, "wallet")).map(|s| s.to_string());
+
+                match get_activities(
+                    &db_pool,
+                    limit,
+                    after.as_deref(),
+                    before.as_deref(),
+                    type_filter.as_deref(),
+                    wallet_filter.as_deref(),
+                ) {
+                    Ok(page) => {
+                        println!("✅ Retrieved {} activities", page.activities.len());
+                        let json = serde_json::to_string(&page).unwrap_or_default();
                         Response::from_string(json)
                     }
                     Err(e) => {
@@ -618,9 +2206,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let (tx, rx) = oneshot::channel();
                 let db_pool_clone = Arc::clone(&db_pool);
                 let blockchain_clone = blockchain.clone();
-                
+                let market_clone = Arc::clone(&market);
+
                 rt_handle.spawn(async move {
-                    let result = create_system_metrics(&db_pool_clone, blockchain_clone.as_deref()).await;
+                    let result = create_system_metrics(&db_pool_clone, blockchain_clone.as_deref(), &market_clone, network).await;
                     let _ = tx.send(result);
                 });
                 
@@ -641,18 +2230,271 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
 
+            // GET /api/prices?symbols=BNBUSDT,...
+            (Method::Get, ["api", "prices"]) => {
+                let symbols: Vec<String> = query
+                    .as_deref()
+                    .and_then(|q| query_param(q, "symbols"))
+                    .map(|s| s.split(',').map(|s| s.to_string()).collect())
+                    .unwrap_or_else(|| vec!["BNBUSDT".to_string()]);
+
+                let (tx, rx) = oneshot::channel();
+                let market_clone = Arc::clone(&market);
+                let symbols_clone = symbols.clone();
+
+                rt_handle.spawn(async move {
+                    let tickers = market_clone.tickers(&symbols_clone).await;
+                    let _ = tx.send(tickers);
+                });
+
+                match rx.blocking_recv() {
+                    Ok(tickers) => {
+                        println!("✅ Retrieved {} price ticker(s)", tickers.len());
+                        let json = serde_json::to_string(&tickers).unwrap_or_default();
+                        Response::from_string(json)
+                    }
+                    Err(_) => {
+                        eprintln!("❌ Channel communication failed for prices");
+                        Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                    }
+                }
+            }
+
             // GET /api/stats
             (Method::Get, ["api", "stats"]) => {
-                match get_wallet_stats(&db_pool) {
-                    Ok(stats) => {
+                let (tx, rx) = oneshot::channel();
+                let db_pool_clone = Arc::clone(&db_pool);
+                let rate_clone = Arc::clone(&rate);
+
+                rt_handle.spawn(async move {
+                    let result = get_wallet_stats(&db_pool_clone, &rate_clone, network).await;
+                    let _ = tx.send(result);
+                });
+
+                match rx.blocking_recv() {
+                    Ok(Ok(stats)) => {
                         println!("✅ Retrieved wallet stats: {} total, {} active", stats.total_wallets, stats.active_wallets);
                         let json = serde_json::to_string(&stats).unwrap_or_default();
                         Response::from_string(json)
                     }
-                    Err(e) => {
+                    Ok(Err(e)) => {
                         eprintln!("❌ Error getting stats: {}", e);
                         Response::from_string(r#"{"error": "Failed to access stats"}"#).with_status_code(500)
                     }
+                    Err(_) => {
+                        eprintln!("❌ Channel communication failed for stats");
+                        Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                    }
+                }
+            }
+
+            // GET /api/rate
+            (Method::Get, ["api", "rate"]) => {
+                let (tx, rx) = oneshot::channel();
+                let rate_clone = Arc::clone(&rate);
+
+                rt_handle.spawn(async move {
+                    let result = rate_clone.usd_per_bnb().await;
+                    let _ = tx.send(result);
+                });
+
+                match rx.blocking_recv() {
+                    Ok(Ok((usd_per_bnb, age))) => {
+                        println!("✅ Retrieved BNB/USD rate: {} ({}s old)", usd_per_bnb, age.as_secs());
+                        let response = RateResponse {
+                            usd_per_bnb: usd_per_bnb.to_string(),
+                            age_seconds: age.as_secs(),
+                        };
+                        let json = serde_json::to_string(&response).unwrap_or_default();
+                        Response::from_string(json)
+                    }
+                    Ok(Err(e)) => {
+                        eprintln!("❌ Error fetching BNB/USD rate: {}", e);
+                        Response::from_string(r#"{"error": "Failed to fetch rate"}"#).with_status_code(500)
+                    }
+                    Err(_) => {
+                        eprintln!("❌ Channel communication failed for rate");
+                        Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                    }
+                }
+            }
+
+            // GET /api/wallets/:address/risk
+            (Method::Get, ["api", "wallets", address, "risk"]) => {
+                if Address::from_str(address).is_err() {
+                    eprintln!("❌ Invalid address for risk screening: {}", address);
+                    Response::from_string(r#"{"error": "Invalid address"}"#).with_status_code(400)
+                } else {
+                    let (tx, rx) = oneshot::channel();
+                    let db_pool_clone = Arc::clone(&db_pool);
+                    let risk_screener_clone = Arc::clone(&risk_screener);
+                    let address_clone = address.to_string();
+
+                    rt_handle.spawn(async move {
+                        let result = risk_screener_clone.screen(&db_pool_clone, network, &address_clone).await;
+                        let _ = tx.send(result);
+                    });
+
+                    match rx.blocking_recv() {
+                        Ok(Ok(verdict)) => {
+                            println!("✅ Screened {} as {:?} risk", address, verdict.tier);
+                            let json = serde_json::to_string(&verdict).unwrap_or_default();
+                            Response::from_string(json)
+                        }
+                        Ok(Err(e)) => {
+                            eprintln!("❌ Error screening {}: {}", address, e);
+                            Response::from_string(r#"{"error": "Failed to screen address"}"#).with_status_code(500)
+                        }
+                        Err(_) => {
+                            eprintln!("❌ Channel communication failed for risk screening");
+                            Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                        }
+                    }
+                }
+            }
+
+            // POST /api/screen — batch risk screening
+            (Method::Post, ["api", "screen"]) => {
+                let mut body = String::new();
+                if request.as_reader().read_to_string(&mut body).is_ok() {
+                    if let Ok(screen_request) = serde_json::from_str::<ScreenRequest>(&body) {
+                        if let Some(invalid) = screen_request.addresses.iter().find(|a| Address::from_str(a).is_err()) {
+                            eprintln!("❌ Invalid address in batch screening request: {}", invalid);
+                            Response::from_string(r#"{"error": "Invalid address"}"#).with_status_code(400)
+                        } else {
+                            let (tx, rx) = oneshot::channel();
+                            let db_pool_clone = Arc::clone(&db_pool);
+                            let risk_screener_clone = Arc::clone(&risk_screener);
+                            let addresses_clone = screen_request.addresses.clone();
+
+                            rt_handle.spawn(async move {
+                                let mut verdicts = Vec::with_capacity(addresses_clone.len());
+                                for address in addresses_clone {
+                                    match risk_screener_clone.screen(&db_pool_clone, network, &address).await {
+                                        Ok(verdict) => verdicts.push(AddressVerdict { address, verdict }),
+                                        Err(e) => eprintln!("⚠️  Failed to screen {}: {}", address, e),
+                                    }
+                                }
+                                let _ = tx.send(verdicts);
+                            });
+
+                            match rx.blocking_recv() {
+                                Ok(verdicts) => {
+                                    println!("✅ Screened {} address(es)", verdicts.len());
+                                    let json = serde_json::to_string(&verdicts).unwrap_or_default();
+                                    Response::from_string(json)
+                                }
+                                Err(_) => {
+                                    eprintln!("❌ Channel communication failed for batch screening");
+                                    Response::from_string(r#"{"error": "Internal communication error"}"#).with_status_code(500)
+                                }
+                            }
+                        }
+                    } else {
+                        eprintln!("❌ Invalid request body format");
+                        Response::from_string(r#"{"error": "Invalid request body"}"#).with_status_code(400)
+                    }
+                } else {
+                    eprintln!("❌ Failed to read request body");
+                    Response::from_string(r#"{"error": "Failed to read request body"}"#).with_status_code(400)
+                }
+            }
+
+            // GET /api/admin/blocklist — drives every risk verdict, so it's
+            // gated behind a bearer token (see `admin_token_authorized`) the
+            // same way wallet export is.
+            (Method::Get, ["api", "admin", "blocklist"]) => {
+                if !admin_token_authorized(&request) {
+                    eprintln!("❌ Unauthorized blocklist read attempt");
+                    Response::from_string(r#"{"error": "Unauthorized"}"#).with_status_code(401)
+                } else {
+                    match risk::get_blocklist(&db_pool) {
+                        Ok(addresses) => {
+                            println!("✅ Retrieved {} blocklisted address(es)", addresses.len());
+                            let json = serde_json::to_string(&addresses).unwrap_or_default();
+                            Response::from_string(json)
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Error getting blocklist: {}", e);
+                            Response::from_string(r#"{"error": "Failed to access blocklist"}"#).with_status_code(500)
+                        }
+                    }
+                }
+            }
+
+            // POST /api/admin/blocklist — same gating as the GET above, since
+            // writing the blocklist is at least as sensitive as reading it.
+            (Method::Post, ["api", "admin", "blocklist"]) => {
+                if !admin_token_authorized(&request) {
+                    eprintln!("❌ Unauthorized blocklist write attempt");
+                    Response::from_string(r#"{"error": "Unauthorized"}"#).with_status_code(401)
+                } else {
+                    let mut body = String::new();
+                    if request.as_reader().read_to_string(&mut body).is_ok() {
+                        if let Ok(entry) = serde_json::from_str::<BlocklistEntryRequest>(&body) {
+                            match risk::add_to_blocklist(&db_pool, &entry.address, entry.reason.as_deref()) {
+                                Ok(()) => {
+                                    println!("✅ Added {} to risk blocklist", entry.address);
+                                    Response::from_string(r#"{"status": "ok"}"#).with_status_code(201)
+                                }
+                                Err(e) => {
+                                    eprintln!("❌ Error updating blocklist: {}", e);
+                                    Response::from_string(r#"{"error": "Failed to update blocklist"}"#).with_status_code(500)
+                                }
+                            }
+                        } else {
+                            eprintln!("❌ Invalid request body format");
+                            Response::from_string(r#"{"error": "Invalid request body"}"#).with_status_code(400)
+                        }
+                    } else {
+                        eprintln!("❌ Failed to read request body");
+                        Response::from_string(r#"{"error": "Failed to read request body"}"#).with_status_code(400)
+                    }
+                }
+            }
+
+            // GET /metrics — Prometheus scrape target
+            (Method::Get, ["metrics"]) => {
+                let (tx, rx) = oneshot::channel();
+                let db_pool_clone = Arc::clone(&db_pool);
+                let rate_clone = Arc::clone(&rate);
+                let blockchain_clone = blockchain.clone();
+
+                rt_handle.spawn(async move {
+                    let stats = get_wallet_stats(&db_pool_clone, &rate_clone, network).await;
+
+                    let rpc_start = Instant::now();
+                    let block_number = match &blockchain_clone {
+                        Some(blockchain) => blockchain.provider.get_block_number().await.ok().map(|n| n.as_u64()),
+                        None => None,
+                    };
+                    let rpc_lag = rpc_start.elapsed().as_secs_f64();
+
+                    let _ = tx.send((stats, block_number, rpc_lag));
+                });
+
+                match rx.blocking_recv() {
+                    Ok((Ok(stats), block_number, rpc_lag)) => {
+                        println!("✅ Rendered Prometheus metrics");
+                        let body = render_prometheus_metrics(
+                            &metrics,
+                            stats.total_wallets as i64,
+                            stats.active_wallets as i64,
+                            block_number.unwrap_or(0),
+                            rpc_lag,
+                        );
+                        Response::from_string(body).with_header(
+                            Header::from_bytes(&b"Content-Type"[..], &b"text/plain; version=0.0.4"[..]).unwrap(),
+                        )
+                    }
+                    Ok((Err(e), _, _)) => {
+                        eprintln!("❌ Error gathering wallet stats for /metrics: {}", e);
+                        Response::from_string("failed to gather metrics").with_status_code(500)
+                    }
+                    Err(_) => {
+                        eprintln!("❌ Channel communication failed for /metrics");
+                        Response::from_string("internal communication error").with_status_code(500)
+                    }
                 }
             }
 
@@ -662,6 +2504,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         };
 
+        metrics.record_request(route, response.status_code().0, request_start.elapsed());
         let cors_response = add_cors_headers(response);
         let _ = request.respond(cors_response);
     }